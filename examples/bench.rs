@@ -0,0 +1,53 @@
+// Times building tables of growing size, to check that adding rows scales linearly.
+// Run with optimizations:
+//
+//     cargo run --release --example bench
+use pinax::{Column, Format, Table, Value};
+use std::time::{Duration, Instant};
+
+fn build(rows: usize, batch: bool) -> Duration {
+    let start = Instant::now();
+    let mut table = Table::with_capacity(
+        [
+            Column::new("Id"),
+            Column::new("Name"),
+            Column::new("Score").with_format(Format::number().decimals(2)),
+        ],
+        rows,
+    );
+
+    let row = |i: usize| {
+        vec![
+            Value::from(i),
+            format!("user-{}", i).into(),
+            (i as f64 * 0.37).into(),
+        ]
+    };
+    if batch {
+        table.add_rows((0..rows).map(row));
+    } else {
+        for i in 0..rows {
+            table.add_row(row(i));
+        }
+    }
+    start.elapsed()
+}
+
+fn main() {
+    println!(
+        "{:>8}  {:>12}  {:>10}  {:>12}  {:>10}",
+        "rows", "add_row", "ns/row", "add_rows", "ns/row"
+    );
+    for rows in [12_500, 25_000, 50_000, 100_000] {
+        let single = build(rows, false);
+        let batch = build(rows, true);
+        println!(
+            "{:>8}  {:>10.1}ms  {:>10}  {:>10.1}ms  {:>10}",
+            rows,
+            single.as_secs_f64() * 1e3,
+            single.as_nanos() / rows as u128,
+            batch.as_secs_f64() * 1e3,
+            batch.as_nanos() / rows as u128,
+        );
+    }
+}
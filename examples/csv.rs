@@ -0,0 +1,15 @@
+// Pretty-prints a CSV file, or standard input when no path is given:
+//
+//     cargo run --example csv -- data.csv
+use pinax::{CsvOptions, Table};
+use std::{env, fs::File, io};
+
+fn main() -> io::Result<()> {
+    let options = CsvOptions::new();
+    let table = match env::args().nth(1) {
+        Some(path) => Table::from_csv(File::open(path)?, &options)?,
+        None => Table::from_csv(io::stdin(), &options)?,
+    };
+    print!("{}", table);
+    Ok(())
+}
@@ -1,13 +1,35 @@
+// Run with the `derive` feature, which the prediction table is built with:
+//
+//     cargo run --example demo --features derive
 use pinax::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, Tabular)]
 struct MLPrediction {
+    #[pinax(header = "Height", align = "right", format = "{:.1}")]
     height: f64,
+    #[pinax(header = "Weight", align = "right", format = "{:.1}")]
     weight: f64,
+    #[pinax(header = "Raw Prediction", align = "center", format = "{:.4}")]
     raw_pred: f64,
+    #[pinax(header = "Predicted", align = "center")]
+    predicted: &'static str,
+    #[pinax(header = "Actual", align = "center")]
     actual: &'static str,
 }
 
+impl MLPrediction {
+    fn new(height: f64, weight: f64, raw_pred: f64, actual: &'static str) -> Self {
+        let predicted = if raw_pred > 0.5 { "Female" } else { "Male" };
+        Self {
+            height,
+            weight,
+            raw_pred,
+            predicted,
+            actual,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Student {
     id: &'static str,
@@ -26,25 +48,29 @@ struct Task {
     due_date: &'static str,
 }
 
-fn create_ml_table() -> Table {
-    Table::builder()
-        .add_column(Column::new("Height").with_alignment(Alignment::Right))
-        .add_column(Column::new("Weight").with_alignment(Alignment::Right))
-        .add_column(Column::new("Raw Prediction").with_alignment(Alignment::Center))
-        .add_column(Column::new("Predicted").with_alignment(Alignment::Center))
-        .add_column(Column::new("Actual").with_alignment(Alignment::Center))
-        .style(BorderStyle::Double)
-        .build()
+fn subject_column(header: &str) -> Column {
+    Column::new(header)
+        .with_alignment(Alignment::Right)
+        .with_aggregate(Aggregate::Mean)
+        .with_footer_format(Format::number().decimals(1))
 }
 
 fn create_student_table() -> Table {
     Table::builder()
-        .add_column(Column::new("Student ID").with_alignment(Alignment::Center))
-        .add_column(Column::new("Name").with_alignment(Alignment::Left))
-        .add_column(Column::new("Math").with_alignment(Alignment::Right))
-        .add_column(Column::new("Science").with_alignment(Alignment::Right))
-        .add_column(Column::new("English").with_alignment(Alignment::Right))
-        .add_column(Column::new("Average").with_alignment(Alignment::Right))
+        .add_column(
+            Column::new("Student ID")
+                .with_alignment(Alignment::Center)
+                .with_aggregate(Aggregate::Count),
+        )
+        .add_column(
+            Column::new("Name")
+                .with_alignment(Alignment::Left)
+                .with_footer("Class Average"),
+        )
+        .add_column(subject_column("Math"))
+        .add_column(subject_column("Science"))
+        .add_column(subject_column("English"))
+        .add_column(subject_column("Average").with_format(Format::number().decimals(1)))
         .style(BorderStyle::Rounded)
         .build()
 }
@@ -62,69 +88,19 @@ fn create_task_table() -> Table {
 
 fn print_ml_predictions() {
     let predictions = vec![
-        MLPrediction {
-            height: 184.3,
-            weight: 89.5,
-            raw_pred: 0.0000,
-            actual: "Male",
-        },
-        MLPrediction {
-            height: 176.8,
-            weight: 79.2,
-            raw_pred: 0.0036,
-            actual: "Male",
-        },
-        MLPrediction {
-            height: 165.9,
-            weight: 58.7,
-            raw_pred: 0.9214,
-            actual: "Female",
-        },
-        MLPrediction {
-            height: 164.1,
-            weight: 57.8,
-            raw_pred: 0.9441,
-            actual: "Female",
-        },
-        MLPrediction {
-            height: 181.9,
-            weight: 85.8,
-            raw_pred: 0.0001,
-            actual: "Male",
-        },
-        MLPrediction {
-            height: 157.9,
-            weight: 50.6,
-            raw_pred: 0.9893,
-            actual: "Female",
-        },
-        MLPrediction {
-            height: 178.4,
-            weight: 81.6,
-            raw_pred: 0.0010,
-            actual: "Male",
-        },
+        MLPrediction::new(184.3, 89.5, 0.0000, "Male"),
+        MLPrediction::new(176.8, 79.2, 0.0036, "Male"),
+        MLPrediction::new(165.9, 58.7, 0.9214, "Female"),
+        MLPrediction::new(164.1, 57.8, 0.9441, "Female"),
+        MLPrediction::new(181.9, 85.8, 0.0001, "Male"),
+        MLPrediction::new(157.9, 50.6, 0.9893, "Female"),
+        MLPrediction::new(178.4, 81.6, 0.0010, "Male"),
     ];
 
-    let mut table = create_ml_table();
-
-    for pred in predictions {
-        let predicted = if pred.raw_pred > 0.5 {
-            "Female"
-        } else {
-            "Male"
-        };
-        table.add_row(vec![
-            format!("{:.1}", pred.height),
-            format!("{:.1}", pred.weight),
-            format!("{:.4}", pred.raw_pred),
-            predicted.to_string(),
-            pred.actual.to_string(),
-        ]);
-    }
-
     println!("\n📊 Machine Learning Gender Predictions");
     println!("=====================================");
+    let mut table = Table::from_rows(&predictions);
+    table.set_border_style(BorderStyle::Double);
     println!("{}", table);
 }
 
@@ -172,12 +148,12 @@ fn print_student_grades() {
     for student in students {
         let avg = (student.math + student.science + student.english) as f64 / 3.0;
         table.add_row(vec![
-            student.id.to_string(),
-            student.name.to_string(),
-            student.math.to_string(),
-            student.science.to_string(),
-            student.english.to_string(),
-            format!("{:.1}", avg),
+            Value::from(student.id),
+            Value::from(student.name),
+            Value::from(student.math),
+            Value::from(student.science),
+            Value::from(student.english),
+            Value::from(avg),
         ]);
     }
 
@@ -332,9 +308,8 @@ fn print_chart_demo() {
     println!("\n{}", line_chart);
 
     // Bar Chart Example
-    let mut bar_chart = Chart::new(ChartType::Bar)
-        .with_title("Daily Activity (Tasks Completed)");
-        
+    let mut bar_chart = Chart::new(ChartType::Bar).with_title("Daily Activity (Tasks Completed)");
+
     bar_chart.add_data_point("Mon", 45.0);
     bar_chart.add_data_point("Tue", 30.0);
     bar_chart.add_data_point("Wed", 60.0);
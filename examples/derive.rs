@@ -0,0 +1,47 @@
+// Builds a table from structs with `#[derive(Tabular)]`. Run with:
+//
+//     cargo run --example derive --features derive
+use pinax::prelude::*;
+
+#[derive(Tabular)]
+struct Prediction {
+    #[pinax(format = "{:.1}")]
+    height: f64,
+    #[pinax(format = "{:.1}")]
+    weight: f64,
+    #[pinax(header = "Raw Prediction", align = "center", format = "{:.4}")]
+    raw_pred: f64,
+    #[pinax(header = "Predicted", width = 10)]
+    predicted: &'static str,
+    #[pinax(header = "Actual")]
+    actual: &'static str,
+    #[pinax(skip)]
+    #[allow(dead_code)]
+    sample_id: u32,
+}
+
+impl Prediction {
+    fn new(sample_id: u32, height: f64, weight: f64, raw_pred: f64, actual: &'static str) -> Self {
+        let predicted = if raw_pred > 0.5 { "Female" } else { "Male" };
+        Self {
+            height,
+            weight,
+            raw_pred,
+            predicted,
+            actual,
+            sample_id,
+        }
+    }
+}
+
+fn main() {
+    let predictions = vec![
+        Prediction::new(1, 184.3, 89.5, 0.0000, "Male"),
+        Prediction::new(2, 176.8, 79.2, 0.0036, "Male"),
+        Prediction::new(3, 165.9, 58.7, 0.9214, "Female"),
+        Prediction::new(4, 164.1, 57.8, 0.9441, "Female"),
+    ];
+
+    let table = Table::from_rows(&predictions);
+    println!("{}", table);
+}
@@ -0,0 +1,56 @@
+// Builds tables straight from serializable structs. Run with:
+//
+//     cargo run --example serde --features serde
+use pinax::Table;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Measurement {
+    height: f64,
+    weight: f64,
+}
+
+#[derive(Serialize)]
+enum Gender {
+    Male,
+    Female,
+}
+
+#[derive(Serialize)]
+struct Prediction {
+    body: Measurement,
+    raw_prediction: f64,
+    actual: Gender,
+    notes: Option<&'static str>,
+    tags: Vec<&'static str>,
+}
+
+fn main() -> Result<(), pinax::SerializeError> {
+    let predictions = [
+        Prediction {
+            body: Measurement {
+                height: 184.3,
+                weight: 89.5,
+            },
+            raw_prediction: 0.0,
+            actual: Gender::Male,
+            notes: None,
+            tags: vec!["train"],
+        },
+        Prediction {
+            body: Measurement {
+                height: 165.9,
+                weight: 58.7,
+            },
+            raw_prediction: 0.9214,
+            actual: Gender::Female,
+            notes: Some("borderline"),
+            tags: vec!["test", "manual"],
+        },
+    ];
+
+    let table = Table::from_serialize(&predictions)?;
+    print!("{}", table);
+    print!("{}", table.to_json());
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// Prints a table row by row as the rows arrive, like a tailed log.
+use pinax::{Column, Overflow, StreamingTableBuilder, Value};
+use std::{io, thread, time::Duration};
+
+fn main() -> io::Result<()> {
+    let mut table = StreamingTableBuilder::new()
+        .add_column(Column::new("Seq"))
+        .add_column(Column::new("Level"))
+        .add_column(
+            Column::new("Message")
+                .with_width(32)
+                .with_max_width(32)
+                .with_overflow(Overflow::Wrap),
+        )
+        .sample_rows(3)
+        .build(io::stdout())?;
+
+    let messages = [
+        ("INFO", "server listening on 0.0.0.0:8080"),
+        ("INFO", "accepted connection from 10.0.0.7"),
+        ("WARN", "request took 1.2s, longer than the 500ms budget"),
+        ("INFO", "connection closed"),
+        ("ERROR", "upstream returned 502 Bad Gateway after three retries"),
+    ];
+    for (seq, (level, message)) in messages.into_iter().enumerate() {
+        table.write_row(vec![Value::from(seq + 1), level.into(), message.into()])?;
+        thread::sleep(Duration::from_millis(200));
+    }
+
+    table.finish()?;
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use pinax::prelude::*;
+use pinax::BorderChars;
+
+fn create_table(theme: Theme) -> Table {
+    fill(Table::builder().theme(theme))
+}
+
+// A double frame and header line around single inner lines.
+fn create_mixed_table() -> Table {
+    let chars = BorderChars {
+        top_left: '╔',
+        top_right: '╗',
+        bottom_left: '╚',
+        bottom_right: '╝',
+        outer_horizontal: '═',
+        outer_vertical: '║',
+        top_separator: '╤',
+        bottom_separator: '╧',
+        left_separator: '╟',
+        right_separator: '╢',
+        header_horizontal: '═',
+        header_cross: '╪',
+        header_left: '╠',
+        header_right: '╣',
+        header_top_separator: '╤',
+        header_bottom_separator: '╧',
+        ..BorderStyle::Single.get_chars()
+    };
+    fill(Table::builder().style(BorderStyle::Custom(chars)))
+}
+
+fn fill(builder: pinax::TableBuilder) -> Table {
+    let mut table = builder
+        .add_column(Column::new("Crate"))
+        .add_column(Column::new("Version"))
+        .add_column(Column::new("Downloads").with_format(Format::number().thousands(',')))
+        .build();
+
+    table.add_row(vec![
+        Value::from("serde"),
+        "1.0.219".into(),
+        512_345_678.into(),
+    ]);
+    table.add_row(vec![
+        Value::from("regex"),
+        "1.11.1".into(),
+        301_222_104.into(),
+    ]);
+    table.add_row(vec![Value::from("pinax"), "0.1.0".into(), 1_024.into()]);
+    table
+}
+
+fn main() {
+    let themes = [
+        Theme::Ascii,
+        Theme::Heavy,
+        Theme::Markdown,
+        Theme::RstGrid,
+        Theme::RstSimple,
+        Theme::Psql,
+        Theme::Mysql,
+        Theme::Blank,
+        Theme::Dots,
+    ];
+
+    for theme in themes {
+        println!("\n{:?}", theme);
+        print!("{}", create_table(theme));
+    }
+
+    println!("\nCustom");
+    print!("{}", create_mixed_table());
+}
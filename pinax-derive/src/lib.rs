@@ -0,0 +1,144 @@
+//! `#[derive(Tabular)]` for [pinax](https://crates.io/crates/pinax), enabled through
+//! its `derive` feature.
+//!
+//! Every named field becomes a column, configured with `#[pinax(...)]`:
+//!
+//! ```text
+//! #[derive(Tabular)]
+//! struct Prediction {
+//!     #[pinax(header = "Raw Prediction", align = "center", format = "{:.4}", width = 10)]
+//!     raw_pred: f64,
+//!     #[pinax(skip)]
+//!     id: u64,
+//! }
+//! ```
+//!
+//! - `header`: the column header, the field name by default.
+//! - `align`: `"left"`, `"center"` or `"right"`.
+//! - `format`: a `format!` string the field is written with, making the cell text.
+//!   Without it the field is converted with `Into<pinax::Value>`, so it keeps its type.
+//! - `width`: the minimum content width of the column.
+//! - `skip`: leaves the field out of the table.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitInt, LitStr};
+
+#[proc_macro_derive(Tabular, attributes(pinax))]
+pub fn derive_tabular(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    header: Option<String>,
+    align: Option<TokenStream2>,
+    format: Option<LitStr>,
+    width: Option<LitInt>,
+    skip: bool,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Tabular can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Tabular can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut columns = Vec::new();
+    let mut cells = Vec::new();
+    for field in fields {
+        let options = field_options(field)?;
+        if options.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+
+        let header = options
+            .header
+            .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string());
+        let mut column = quote!(::pinax::Column::new(#header));
+        if let Some(align) = options.align {
+            column = quote!(#column.with_alignment(#align));
+        }
+        if let Some(width) = options.width {
+            column = quote!(#column.with_width(#width));
+        }
+        columns.push(column);
+
+        cells.push(match options.format {
+            Some(format) => quote!(::pinax::Value::from(::std::format!(#format, self.#ident))),
+            None => quote!(::pinax::Value::from(::core::clone::Clone::clone(&self.#ident))),
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::pinax::Tabular for #name #ty_generics #where_clause {
+            fn columns() -> ::std::vec::Vec<::pinax::Column> {
+                ::std::vec![#(#columns),*]
+            }
+
+            fn to_row(&self) -> ::std::vec::Vec<::pinax::Value> {
+                ::std::vec![#(#cells),*]
+            }
+        }
+    })
+}
+
+fn field_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("pinax"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else if meta.path.is_ident("header") {
+                options.header = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("format") {
+                options.format = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("width") {
+                options.width = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("align") {
+                let align: LitStr = meta.value()?.parse()?;
+                options.align = Some(match align.value().as_str() {
+                    "left" => quote!(::pinax::Alignment::Left),
+                    "center" => quote!(::pinax::Alignment::Center),
+                    "right" => quote!(::pinax::Alignment::Right),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            align,
+                            "expected \"left\", \"center\" or \"right\"",
+                        ))
+                    }
+                });
+            } else {
+                return Err(meta.error(
+                    "unknown pinax attribute, expected header, align, format, width or skip",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
@@ -0,0 +1,66 @@
+use pinax::{CsvOptions, Table, Tabular, Value};
+
+#[derive(Tabular)]
+struct Prediction {
+    #[pinax(header = "Raw Prediction", align = "right", format = "{:.2}")]
+    raw_pred: f64,
+    label: &'static str,
+    count: u32,
+    #[pinax(skip)]
+    #[allow(dead_code)]
+    id: u64,
+    r#type: &'static str,
+}
+
+fn predictions() -> Vec<Prediction> {
+    vec![
+        Prediction {
+            raw_pred: 0.9214,
+            label: "Female",
+            count: 3,
+            id: 1,
+            r#type: "test",
+        },
+        Prediction {
+            raw_pred: 0.0036,
+            label: "Male",
+            count: 12,
+            id: 2,
+            r#type: "train",
+        },
+    ]
+}
+
+#[test]
+fn to_row_formats_and_converts_fields() {
+    let rows: Vec<Vec<Value>> = predictions().iter().map(Tabular::to_row).collect();
+    assert_eq!(
+        rows[0],
+        vec![
+            Value::from("0.92".to_string()),
+            Value::from("Female"),
+            Value::from(3u32),
+            Value::from("test"),
+        ]
+    );
+}
+
+#[test]
+fn columns_skip_fields_and_use_headers() {
+    assert_eq!(Prediction::columns().len(), 4);
+
+    let mut csv = Vec::new();
+    Table::from_rows(&predictions())
+        .to_csv(&mut csv, &CsvOptions::new())
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "Raw Prediction,label,count,type\n0.92,Female,3,test\n0.00,Male,12,train\n"
+    );
+}
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use pinax::Tabular;
+
+#[derive(Tabular)]
+struct Row {
+    #[pinax(align = "middle")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use pinax::Tabular;
+
+#[derive(Tabular)]
+struct Row {
+    #[pinax(width = "wide")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use pinax::Tabular;
+
+#[derive(Tabular)]
+enum Row {
+    Name(String),
+}
+
+fn main() {}
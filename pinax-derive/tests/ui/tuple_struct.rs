@@ -0,0 +1,6 @@
+use pinax::Tabular;
+
+#[derive(Tabular)]
+struct Row(String, u32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use pinax::Tabular;
+
+#[derive(Tabular)]
+struct Row {
+    #[pinax(colour = "red")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,149 @@
+#!/usr/bin/env python3
+"""Generates src/width/tables.rs from the Unicode Character Database.
+
+Usage:
+
+    python3 scripts/width_tables.py [UCD_DIR] > src/width/tables.rs
+
+The data files are read from UCD_DIR when given, and downloaded from unicode.org
+otherwise:
+
+    EastAsianWidth.txt
+    DerivedGeneralCategory.txt   (extracted/)
+    GraphemeBreakProperty.txt    (auxiliary/)
+    emoji-variation-sequences.txt (emoji/)
+"""
+
+import os
+import re
+import sys
+import urllib.request
+
+UNICODE_VERSION = "17.0.0"
+BASE_URL = f"https://www.unicode.org/Public/{UNICODE_VERSION}/ucd"
+FILES = {
+    "EastAsianWidth.txt": "EastAsianWidth.txt",
+    "DerivedGeneralCategory.txt": "extracted/DerivedGeneralCategory.txt",
+    "GraphemeBreakProperty.txt": "auxiliary/GraphemeBreakProperty.txt",
+    "emoji-variation-sequences.txt": "emoji/emoji-variation-sequences.txt",
+}
+
+# Skin tone modifiers are grapheme extenders, but stand alone as wide emoji swatches.
+EMOJI_MODIFIERS = set(range(0x1F3FB, 0x1F3FF + 1))
+# Format characters that terminals draw: SOFT HYPHEN and the prepended concatenation
+# marks, which span the digits that follow them.
+VISIBLE_FORMAT = {0x00AD, 0x0600, 0x0601, 0x0602, 0x0603, 0x0604, 0x0605, 0x06DD, 0x070F,
+                  0x0890, 0x0891, 0x08E2, 0x110BD, 0x110CD}
+# The rest of the tag and variation selector block is reserved as default ignorable.
+DEFAULT_IGNORABLE_BLOCK = set(range(0xE0000, 0xE0FFF + 1))
+
+
+def read(directory, name):
+    if directory is not None:
+        with open(os.path.join(directory, name), encoding="utf-8") as file:
+            return file.read()
+    with urllib.request.urlopen(f"{BASE_URL}/{FILES[name]}") as response:
+        return response.read().decode("utf-8")
+
+
+def properties(text):
+    """Yields (code points, value) for every `start..end ; value` line."""
+    for line in text.splitlines():
+        line = line.split("#", 1)[0].strip()
+        if not line:
+            continue
+        fields = [field.strip() for field in line.split(";")]
+        bounds = fields[0].split("..")
+        start = int(bounds[0], 16)
+        end = int(bounds[-1], 16)
+        yield range(start, end + 1), fields[1]
+
+
+def collect(text, values):
+    points = set()
+    for span, value in properties(text):
+        if value in values:
+            points.update(span)
+    return points
+
+
+def variation_bases(text):
+    bases = set()
+    for line in text.splitlines():
+        line = line.split("#", 1)[0].strip()
+        match = re.match(r"([0-9A-F]+) FE0F\s*;", line)
+        if match:
+            bases.add(int(match.group(1), 16))
+    return bases
+
+
+def ranges(points):
+    result = []
+    for point in sorted(points):
+        if result and result[-1][1] + 1 == point:
+            result[-1][1] = point
+        else:
+            result.append([point, point])
+    return result
+
+
+def table(name, doc, points):
+    lines = [f"/// {line}".rstrip() for line in doc]
+    lines.append(f"pub(super) const {name}: &[(u32, u32)] = &[")
+    lines.extend(f"    (0x{start:04X}, 0x{end:04X})," for start, end in ranges(points))
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def main():
+    directory = sys.argv[1] if len(sys.argv) > 1 else None
+
+    category = read(directory, "DerivedGeneralCategory.txt")
+    grapheme = read(directory, "GraphemeBreakProperty.txt")
+    east_asian = read(directory, "EastAsianWidth.txt")
+    variation = read(directory, "emoji-variation-sequences.txt")
+
+    zero_width = (
+        collect(category, {"Mn", "Me", "Cf"})
+        | collect(grapheme, {"Extend", "V", "T"})
+        | DEFAULT_IGNORABLE_BLOCK
+    ) - EMOJI_MODIFIERS - VISIBLE_FORMAT
+    wide = collect(east_asian, {"W", "F"}) - zero_width
+    emoji_variation = variation_bases(variation) - wide
+
+    print("//! Code point range tables used by the display width engine.")
+    print("//!")
+    print(f"//! Generated by `scripts/width_tables.py` from the Unicode {UNICODE_VERSION[:-2]} data files;")
+    print("//! do not edit by hand. Every table is sorted and its ranges are inclusive and")
+    print("//! non-overlapping so lookups can binary search them.")
+    print()
+    print(table(
+        "ZERO_WIDTH",
+        [
+            "Code points that occupy no columns: nonspacing and enclosing marks, format",
+            "characters, grapheme extenders and Hangul medial/final jamo.",
+        ],
+        zero_width,
+    ))
+    print()
+    print(table(
+        "WIDE",
+        [
+            "Code points with an East Asian Width of `W` or `F`, which includes emoji with",
+            "default emoji presentation.",
+        ],
+        wide,
+    ))
+    print()
+    print(table(
+        "EMOJI_VARIATION",
+        [
+            "Narrow code points that switch to a two column emoji presentation when followed",
+            "by VARIATION SELECTOR-16.",
+        ],
+        emoji_variation,
+    ))
+
+
+if __name__ == "__main__":
+    main()
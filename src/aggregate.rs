@@ -0,0 +1,159 @@
+use crate::{sort::Comparator, value::Value};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+type AggregateFn = dyn Fn(&[&Value]) -> Value + Send + Sync;
+
+/// A summary of a column's data cells, shown in the table footer.
+///
+/// Numeric aggregates use integer and float cells as well as text that parses as a
+/// number; other cells are skipped. Empty cells are never counted.
+#[derive(Clone)]
+pub enum Aggregate {
+    Sum,
+    Mean,
+    /// The smallest cell, compared like [`Comparator::Auto`].
+    Min,
+    /// The largest cell, compared like [`Comparator::Auto`].
+    Max,
+    Count,
+    CountDistinct,
+    Custom(Arc<AggregateFn>),
+}
+
+impl Aggregate {
+    pub fn custom(aggregate: impl Fn(&[&Value]) -> Value + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(aggregate))
+    }
+
+    pub(crate) fn compute(&self, values: &[&Value]) -> Value {
+        let present: Vec<&Value> = values.iter().copied().filter(|v| !v.is_empty()).collect();
+        let numbers: Vec<f64> = present.iter().filter_map(|v| v.as_f64()).collect();
+
+        match self {
+            Self::Sum if numbers.is_empty() => Value::Empty,
+            Self::Sum => {
+                let integers: Option<Vec<i64>> = present
+                    .iter()
+                    .map(|value| match value {
+                        Value::Integer(value) => Some(*value),
+                        _ => None,
+                    })
+                    .collect();
+                match integers.and_then(|values| {
+                    values
+                        .into_iter()
+                        .try_fold(0i64, |sum, value| sum.checked_add(value))
+                }) {
+                    Some(sum) => Value::Integer(sum),
+                    None => Value::Float(numbers.iter().sum()),
+                }
+            }
+            Self::Mean if numbers.is_empty() => Value::Empty,
+            Self::Mean => Value::Float(numbers.iter().sum::<f64>() / numbers.len() as f64),
+            Self::Min => present
+                .into_iter()
+                .min_by(|a, b| Comparator::Auto.compare(a, b))
+                .cloned()
+                .unwrap_or_default(),
+            Self::Max => present
+                .into_iter()
+                .max_by(|a, b| Comparator::Auto.compare(a, b))
+                .cloned()
+                .unwrap_or_default(),
+            Self::Count => Value::from(present.len()),
+            Self::CountDistinct => {
+                let distinct: HashSet<String> = present.iter().map(|v| v.to_string()).collect();
+                Value::from(distinct.len())
+            }
+            Self::Custom(aggregate) => aggregate(values),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compute(aggregate: Aggregate, values: &[Value]) -> Value {
+        let values: Vec<&Value> = values.iter().collect();
+        aggregate.compute(&values)
+    }
+
+    fn mixed() -> Vec<Value> {
+        vec![
+            Value::from(4),
+            Value::from(2.5),
+            Value::from("1.5"),
+            Value::from("n/a"),
+            Value::Empty,
+            Value::from(""),
+            Value::from(true),
+        ]
+    }
+
+    #[test]
+    fn sum() {
+        assert_eq!(
+            compute(Aggregate::Sum, &[1, 2, 3].map(Value::from)),
+            Value::Integer(6)
+        );
+        assert_eq!(compute(Aggregate::Sum, &mixed()), Value::Float(8.0));
+        // Integer sums that overflow fall back to floats.
+        assert_eq!(
+            compute(Aggregate::Sum, &[i64::MAX, 1].map(Value::from)),
+            Value::Float(i64::MAX as f64 + 1.0)
+        );
+        assert_eq!(
+            compute(Aggregate::Sum, &[Value::from("x"), Value::Empty]),
+            Value::Empty
+        );
+        assert_eq!(compute(Aggregate::Sum, &[]), Value::Empty);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(
+            compute(Aggregate::Mean, &[1, 2].map(Value::from)),
+            Value::Float(1.5)
+        );
+        assert_eq!(compute(Aggregate::Mean, &mixed()), Value::Float(8.0 / 3.0));
+        assert_eq!(compute(Aggregate::Mean, &[Value::Empty]), Value::Empty);
+        assert_eq!(compute(Aggregate::Mean, &[]), Value::Empty);
+    }
+
+    #[test]
+    fn min_and_max() {
+        let numbers = [3, -1, 7].map(Value::from);
+        assert_eq!(compute(Aggregate::Min, &numbers), Value::Integer(-1));
+        assert_eq!(compute(Aggregate::Max, &numbers), Value::Integer(7));
+
+        // Empty cells are skipped and kinds are ranked like Comparator::Auto.
+        assert_eq!(compute(Aggregate::Min, &mixed()), Value::from("1.5"));
+        assert_eq!(compute(Aggregate::Max, &mixed()), Value::from("n/a"));
+        let words = ["pear", "", "apple"].map(Value::from);
+        assert_eq!(compute(Aggregate::Min, &words), Value::from("apple"));
+
+        assert_eq!(compute(Aggregate::Min, &[Value::Empty]), Value::Empty);
+        assert_eq!(compute(Aggregate::Max, &[]), Value::Empty);
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(compute(Aggregate::Count, &mixed()), Value::Integer(5));
+        assert_eq!(compute(Aggregate::Count, &[]), Value::Integer(0));
+
+        let repeated = ["a", "b", "a", "", "b"].map(Value::from);
+        assert_eq!(
+            compute(Aggregate::CountDistinct, &repeated),
+            Value::Integer(2)
+        );
+        assert_eq!(compute(Aggregate::CountDistinct, &[]), Value::Integer(0));
+    }
+
+    #[test]
+    fn custom_sees_every_cell() {
+        let cells = Aggregate::custom(|values| Value::from(values.len()));
+        assert_eq!(compute(cells, &mixed()), Value::Integer(7));
+    }
+}
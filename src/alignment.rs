@@ -1,3 +1,5 @@
+use crate::{truncate::Truncation, width::display_width};
+
 #[derive(Debug, Clone, Copy)]
 pub enum Alignment {
     Left,
@@ -6,17 +8,46 @@ pub enum Alignment {
 }
 
 impl Alignment {
-    pub(crate) fn format_content(&self, content: &str, width: usize) -> String {
-        let truncated = if content.len() > width {
-            format!("{}...", &content[..width.saturating_sub(3)])
-        } else {
-            content.to_string()
+    pub(crate) fn format_content(
+        &self,
+        content: &str,
+        width: usize,
+        truncation: &Truncation,
+    ) -> String {
+        let truncated = truncation.truncate(content, width);
+        format!(" {} ", self.pad(&truncated, width))
+    }
+
+    // `format!` width specifiers count chars, not terminal columns, so padding is
+    // computed from the display width instead.
+    pub(crate) fn pad(&self, content: &str, width: usize) -> String {
+        let fill = width.saturating_sub(display_width(content));
+        let (left, right) = match self {
+            Self::Left => (0, fill),
+            Self::Right => (fill, 0),
+            Self::Center => (fill / 2, fill - fill / 2),
         };
+        format!("{}{}{}", " ".repeat(left), content, " ".repeat(right))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum VerticalAlignment {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
 
+impl VerticalAlignment {
+    // Number of blank lines placed above `lines` lines of content in a row that is
+    // `height` lines tall.
+    pub(crate) fn offset(&self, lines: usize, height: usize) -> usize {
+        let fill = height.saturating_sub(lines);
         match self {
-            Self::Left => format!(" {:<width$} ", truncated, width = width),
-            Self::Right => format!(" {:>width$} ", truncated, width = width),
-            Self::Center => format!(" {:^width$} ", truncated, width = width),
+            Self::Top => 0,
+            Self::Middle => fill / 2,
+            Self::Bottom => fill,
         }
     }
 }
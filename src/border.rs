@@ -1,67 +1,304 @@
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum BorderStyle {
     #[default]
     Single,
     Double,
     Rounded,
+    /// Glyphs defined glyph by glyph, see [`BorderChars`].
+    Custom(BorderChars),
 }
 
-pub(crate) struct BorderChars {
+/// Every glyph used to draw the lines of a table.
+///
+/// The frame, the lines inside the table and the line under the header each have
+/// their own glyphs, so mixed styles such as a double frame around single inner
+/// lines can be expressed. Start from a preset and override what differs:
+///
+/// ```text
+/// BorderChars {
+///     top_left: '╔', top_right: '╗', bottom_left: '╚', bottom_right: '╝',
+///     outer_horizontal: '═', outer_vertical: '║',
+///     top_separator: '╤', bottom_separator: '╧', left_separator: '╟', right_separator: '╢',
+///     ..BorderStyle::Single.get_chars()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderChars {
     pub top_left: char,
     pub top_right: char,
     pub bottom_left: char,
     pub bottom_right: char,
-    pub horizontal: char,
-    pub vertical: char,
-    pub left_separator: char,
-    pub right_separator: char,
+    /// The top and bottom lines of the frame.
+    pub outer_horizontal: char,
+    /// The left and right lines of the frame.
+    pub outer_vertical: char,
+    /// Where a line between columns meets the top of the frame.
     pub top_separator: char,
+    /// Where a line between columns meets the bottom of the frame.
     pub bottom_separator: char,
+    /// Where a line between rows meets the left of the frame.
+    pub left_separator: char,
+    /// Where a line between rows meets the right of the frame.
+    pub right_separator: char,
+
+    /// Lines between rows.
+    pub horizontal: char,
+    /// Lines between columns.
+    pub vertical: char,
     pub cross: char,
+    /// Where a line between columns starts below a line between rows, because a
+    /// merged cell interrupts it above.
+    pub inner_top_separator: char,
+    /// Where a line between columns ends above a line between rows.
+    pub inner_bottom_separator: char,
+    /// Where a line between rows starts to the right of a line between columns.
+    pub inner_left_separator: char,
+    /// Where a line between rows ends to the left of a line between columns.
+    pub inner_right_separator: char,
+
+    /// The line between the header and the data rows.
+    pub header_horizontal: char,
+    pub header_cross: char,
+    /// Where the header line meets the left of the frame.
+    pub header_left: char,
+    /// Where the header line meets the right of the frame.
+    pub header_right: char,
+    /// Where a line between columns starts below the header line.
+    pub header_top_separator: char,
+    /// Where a line between columns ends above the header line.
+    pub header_bottom_separator: char,
 }
 
 impl BorderStyle {
-    pub(crate) fn get_chars(&self) -> BorderChars {
+    pub fn get_chars(&self) -> BorderChars {
         match self {
-            Self::Single => BorderChars {
-                top_left: '┌',
-                top_right: '┐',
-                bottom_left: '└',
-                bottom_right: '┘',
-                horizontal: '─',
-                vertical: '│',
-                left_separator: '├',
-                right_separator: '┤',
-                top_separator: '┬',
-                bottom_separator: '┴',
-                cross: '┼',
-            },
-            Self::Double => BorderChars {
-                top_left: '╔',
-                top_right: '╗',
-                bottom_left: '╚',
-                bottom_right: '╝',
-                horizontal: '═',
-                vertical: '║',
-                left_separator: '╠',
-                right_separator: '╣',
-                top_separator: '╦',
-                bottom_separator: '╩',
-                cross: '╬',
-            },
-            Self::Rounded => BorderChars {
-                top_left: '╭',
-                top_right: '╮',
-                bottom_left: '╰',
-                bottom_right: '╯',
-                horizontal: '─',
-                vertical: '│',
-                left_separator: '├',
-                right_separator: '┤',
-                top_separator: '┬',
-                bottom_separator: '┴',
-                cross: '┼',
+            Self::Single => BorderChars::single_weight(
+                ['┌', '┐', '└', '┘'],
+                '─',
+                '│',
+                ['┬', '┴', '├', '┤'],
+                '┼',
+            ),
+            Self::Double => BorderChars::single_weight(
+                ['╔', '╗', '╚', '╝'],
+                '═',
+                '║',
+                ['╦', '╩', '╠', '╣'],
+                '╬',
+            ),
+            Self::Rounded => BorderChars::single_weight(
+                ['╭', '╮', '╰', '╯'],
+                '─',
+                '│',
+                ['┬', '┴', '├', '┤'],
+                '┼',
+            ),
+            Self::Custom(chars) => *chars,
+        }
+    }
+}
+
+/// Where a horizontal line is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RuleKind {
+    Top,
+    Header,
+    Inner,
+    Bottom,
+}
+
+impl BorderChars {
+    // A set whose frame, inner and header lines all look the same. Separators are
+    // given as top, bottom, left and right.
+    pub(crate) fn single_weight(
+        [top_left, top_right, bottom_left, bottom_right]: [char; 4],
+        horizontal: char,
+        vertical: char,
+        [top, bottom, left, right]: [char; 4],
+        cross: char,
+    ) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            outer_horizontal: horizontal,
+            outer_vertical: vertical,
+            top_separator: top,
+            bottom_separator: bottom,
+            left_separator: left,
+            right_separator: right,
+            horizontal,
+            vertical,
+            cross,
+            inner_top_separator: top,
+            inner_bottom_separator: bottom,
+            inner_left_separator: left,
+            inner_right_separator: right,
+            header_horizontal: horizontal,
+            header_cross: cross,
+            header_left: left,
+            header_right: right,
+            header_top_separator: top,
+            header_bottom_separator: bottom,
+        }
+    }
+
+    /// A set where every corner and junction is the same glyph.
+    pub(crate) fn uniform(horizontal: char, vertical: char, junction: char) -> Self {
+        Self::single_weight([junction; 4], horizontal, vertical, [junction; 4], junction)
+    }
+
+    pub(crate) fn rule_horizontal(&self, kind: RuleKind) -> char {
+        match kind {
+            RuleKind::Top | RuleKind::Bottom => self.outer_horizontal,
+            RuleKind::Header => self.header_horizontal,
+            RuleKind::Inner => self.horizontal,
+        }
+    }
+
+    /// The glyph where a rule meets the left side of the frame. `through` is false
+    /// where a merged cell crosses the rule next to the frame.
+    pub(crate) fn rule_left(&self, kind: RuleKind, through: bool) -> char {
+        match kind {
+            RuleKind::Top => self.top_left,
+            RuleKind::Bottom => self.bottom_left,
+            _ if !through => self.outer_vertical,
+            RuleKind::Header => self.header_left,
+            RuleKind::Inner => self.left_separator,
+        }
+    }
+
+    /// The glyph where a rule meets the right side of the frame.
+    pub(crate) fn rule_right(&self, kind: RuleKind, through: bool) -> char {
+        match kind {
+            RuleKind::Top => self.top_right,
+            RuleKind::Bottom => self.bottom_right,
+            _ if !through => self.outer_vertical,
+            RuleKind::Header => self.header_right,
+            RuleKind::Inner => self.right_separator,
+        }
+    }
+
+    /// Picks the glyph where a rule crosses the boundary between two columns, given
+    /// which of the four directions a line leaves the junction in.
+    pub(crate) fn rule_junction(
+        &self,
+        kind: RuleKind,
+        up: bool,
+        down: bool,
+        left: bool,
+        right: bool,
+    ) -> char {
+        match kind {
+            RuleKind::Top if down => self.top_separator,
+            RuleKind::Bottom if up => self.bottom_separator,
+            RuleKind::Top | RuleKind::Bottom => self.outer_horizontal,
+            RuleKind::Header => match (up, down, left, right) {
+                (true, true, true, true) => self.header_cross,
+                (false, true, true, true) => self.header_top_separator,
+                (true, false, true, true) => self.header_bottom_separator,
+                (false, false, true, true) => self.header_horizontal,
+                _ => self.inner_junction(up, down, left, right),
             },
+            RuleKind::Inner => self.inner_junction(up, down, left, right),
+        }
+    }
+
+    fn inner_junction(&self, up: bool, down: bool, left: bool, right: bool) -> char {
+        match (up, down, left, right) {
+            (true, true, true, true) => self.cross,
+            (true, true, false, true) => self.inner_left_separator,
+            (true, true, true, false) => self.inner_right_separator,
+            (false, true, true, true) => self.inner_top_separator,
+            (true, false, true, true) => self.inner_bottom_separator,
+            (_, _, true, _) | (_, _, _, true) => self.horizontal,
+            (true, _, _, _) | (_, true, _, _) => self.vertical,
+            (false, false, false, false) => ' ',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars() -> BorderChars {
+        BorderChars {
+            top_left: 'a',
+            top_right: 'b',
+            bottom_left: 'c',
+            bottom_right: 'd',
+            outer_horizontal: 'e',
+            outer_vertical: 'f',
+            top_separator: 'g',
+            bottom_separator: 'h',
+            left_separator: 'i',
+            right_separator: 'j',
+            horizontal: 'k',
+            vertical: 'l',
+            cross: 'm',
+            inner_top_separator: 'n',
+            inner_bottom_separator: 'o',
+            inner_left_separator: 'p',
+            inner_right_separator: 'q',
+            header_horizontal: 'r',
+            header_cross: 's',
+            header_left: 't',
+            header_right: 'u',
+            header_top_separator: 'v',
+            header_bottom_separator: 'w',
         }
     }
+
+    #[test]
+    fn custom_chars_are_used_as_given() {
+        assert_eq!(BorderStyle::Custom(chars()).get_chars(), chars());
+    }
+
+    #[test]
+    fn frame_glyphs() {
+        let chars = chars();
+        let sides = |kind, through| {
+            (
+                chars.rule_horizontal(kind),
+                chars.rule_left(kind, through),
+                chars.rule_right(kind, through),
+            )
+        };
+        assert_eq!(sides(RuleKind::Top, true), ('e', 'a', 'b'));
+        assert_eq!(sides(RuleKind::Bottom, true), ('e', 'c', 'd'));
+        assert_eq!(sides(RuleKind::Inner, true), ('k', 'i', 'j'));
+        assert_eq!(sides(RuleKind::Header, true), ('r', 't', 'u'));
+        // A merged cell next to the frame interrupts the rule.
+        assert_eq!(sides(RuleKind::Inner, false), ('k', 'f', 'f'));
+        assert_eq!(sides(RuleKind::Header, false), ('r', 'f', 'f'));
+    }
+
+    #[test]
+    fn junction_glyphs() {
+        let chars = chars();
+        let junction =
+            |kind, up, down, left, right| chars.rule_junction(kind, up, down, left, right);
+        assert_eq!(junction(RuleKind::Top, false, true, true, true), 'g');
+        assert_eq!(junction(RuleKind::Top, false, false, true, true), 'e');
+        assert_eq!(junction(RuleKind::Bottom, true, false, true, true), 'h');
+        assert_eq!(junction(RuleKind::Bottom, false, false, true, true), 'e');
+
+        assert_eq!(junction(RuleKind::Inner, true, true, true, true), 'm');
+        assert_eq!(junction(RuleKind::Inner, false, true, true, true), 'n');
+        assert_eq!(junction(RuleKind::Inner, true, false, true, true), 'o');
+        assert_eq!(junction(RuleKind::Inner, true, true, false, true), 'p');
+        assert_eq!(junction(RuleKind::Inner, true, true, true, false), 'q');
+        assert_eq!(junction(RuleKind::Inner, false, false, true, true), 'k');
+        assert_eq!(junction(RuleKind::Inner, true, true, false, false), 'l');
+        assert_eq!(junction(RuleKind::Inner, false, false, false, false), ' ');
+
+        assert_eq!(junction(RuleKind::Header, true, true, true, true), 's');
+        assert_eq!(junction(RuleKind::Header, false, true, true, true), 'v');
+        assert_eq!(junction(RuleKind::Header, true, false, true, true), 'w');
+        assert_eq!(junction(RuleKind::Header, false, false, true, true), 'r');
+        // Where a merged cell crosses the header line, the inner glyphs apply.
+        assert_eq!(junction(RuleKind::Header, true, true, false, true), 'p');
+    }
 }
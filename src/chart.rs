@@ -1,3 +1,4 @@
+use crate::{alignment::Alignment, width::display_width};
 use std::fmt;
 
 #[derive(Debug, Clone, Copy)]
@@ -45,7 +46,12 @@ impl Chart {
         for (label, value) in &self.data {
             let bar_width = ((value / max_value) * 40.0) as usize;
             let bar = "█".repeat(bar_width);
-            output.push(format!("{:<6} │ {:<40} {:.1}", label, bar, value));
+            output.push(format!(
+                "{} │ {:<40} {:.1}",
+                Alignment::Left.pad(label, 6),
+                bar,
+                value
+            ));
         }
 
         output
@@ -90,7 +96,7 @@ impl Chart {
             // Draw connecting line
             let dx = x2 - x1;
             let dy = y2 as i32 - y1 as i32;
-            let steps = dx.max(dy.abs() as usize);
+            let steps = dx.max(dy.unsigned_abs() as usize);
             
             for step in 1..steps {
                 let x = x1 + step;
@@ -130,7 +136,7 @@ impl Chart {
         // Add labels with improved spacing
         let mut labels = String::from("            ");
         for (i, (label, _)) in self.data.iter().enumerate() {
-            labels.push_str(&format!("{:<11}", label));
+            labels.push_str(&Alignment::Left.pad(label, 11));
             if i < self.data.len() - 1 {
                 labels.push(' ');
             }
@@ -146,7 +152,7 @@ impl fmt::Display for Chart {
         // Write title if present
         if let Some(title) = &self.title {
             writeln!(f, "{}", title)?;
-            writeln!(f, "{}", "=".repeat(title.len()))?;
+            writeln!(f, "{}", "=".repeat(display_width(title)))?;
             writeln!(f)?; // Add extra newline after title
         }
 
@@ -1,31 +1,72 @@
-use crate::alignment::Alignment;
+use crate::{
+    aggregate::Aggregate,
+    alignment::{Alignment, VerticalAlignment},
+    format::Format,
+    rule::Rule,
+    style::Style,
+    truncate::Truncation,
+    value::Value,
+    width::display_width,
+    wrap::wrap_text,
+};
 
 const MIN_PADDING: usize = 2;
 const DEFAULT_PADDING: usize = 4;
 
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Overflow {
+    /// Cut each line of the cell to the column width.
+    #[default]
+    Truncate,
+    /// Word-wrap the cell across as many lines as it needs.
+    Wrap,
+}
+
 #[derive(Clone)]
 pub struct Column {
     pub(crate) header: String,
-    pub(crate) alignment: Alignment,
+    pub(crate) alignment: Option<Alignment>,
     pub(crate) min_width: usize,
     pub(crate) max_width: Option<usize>,
     pub(crate) padding: usize,
+    pub(crate) truncation: Truncation,
+    pub(crate) overflow: Overflow,
+    pub(crate) vertical_alignment: VerticalAlignment,
+    pub(crate) priority: u32,
+    pub(crate) style: Style,
+    pub(crate) header_style: Style,
+    pub(crate) rules: Vec<Rule>,
+    pub(crate) format: Option<Format>,
+    pub(crate) aggregate: Option<Aggregate>,
+    pub(crate) footer: Option<String>,
+    pub(crate) footer_format: Option<Format>,
 }
 
 impl Column {
     pub fn new(header: impl Into<String>) -> Self {
         let header = header.into();
         Self {
-            min_width: header.len(),
+            min_width: display_width(&header),
             header,
-            alignment: Alignment::Left,
+            alignment: None,
             max_width: None,
             padding: DEFAULT_PADDING,
+            truncation: Truncation::default(),
+            overflow: Overflow::default(),
+            vertical_alignment: VerticalAlignment::default(),
+            priority: 0,
+            style: Style::default(),
+            header_style: Style::default(),
+            rules: Vec::new(),
+            format: None,
+            aggregate: None,
+            footer: None,
+            footer_format: None,
         }
     }
 
     pub fn with_alignment(mut self, alignment: Alignment) -> Self {
-        self.alignment = alignment;
+        self.alignment = Some(alignment);
         self
     }
 
@@ -44,6 +85,128 @@ impl Column {
         self
     }
 
+    pub fn with_truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn with_vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Sets how important the column is when the table has to fit a narrow
+    /// terminal. Columns with a lower priority are shrunk and hidden first.
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the style of every data cell in the column.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Adds a conditional highlight for the column's data cells. Rules are checked
+    /// in the order they were added and every matching rule's style is applied.
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Sets how numeric cells in the column are displayed.
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Shows a summary of the column's cells in the table footer.
+    pub fn with_aggregate(mut self, aggregate: Aggregate) -> Self {
+        self.aggregate = Some(aggregate);
+        self
+    }
+
+    /// Shows fixed text, such as a "Total" label, in the table footer. An aggregate
+    /// takes precedence over this text.
+    pub fn with_footer(mut self, text: impl Into<String>) -> Self {
+        self.footer = Some(text.into());
+        self
+    }
+
+    /// Sets how the footer value is displayed, for example to show a mean of
+    /// integer cells with decimals. Defaults to the column's format.
+    pub fn with_footer_format(mut self, format: Format) -> Self {
+        self.footer_format = Some(format);
+        self
+    }
+
+    pub(crate) fn format_footer(&self, value: &Value) -> String {
+        match self.footer_format.as_ref().or(self.format.as_ref()) {
+            Some(format) => format.format(value),
+            None => value.to_string(),
+        }
+    }
+
+    pub(crate) fn has_footer(&self) -> bool {
+        self.aggregate.is_some() || self.footer.is_some()
+    }
+
+    pub(crate) fn footer_value(&self, values: &[&Value]) -> Value {
+        match (&self.aggregate, &self.footer) {
+            (Some(aggregate), _) => aggregate.compute(values),
+            (None, Some(text)) => Value::from(text),
+            (None, None) => Value::Empty,
+        }
+    }
+
+    pub(crate) fn format_value(&self, value: &Value) -> String {
+        match &self.format {
+            Some(format) => format.format(value),
+            None => value.to_string(),
+        }
+    }
+
+    // Without an explicit alignment, numbers are right-aligned and everything else
+    // is left-aligned.
+    pub(crate) fn alignment_for(&self, value: &Value) -> Alignment {
+        match self.alignment {
+            Some(alignment) => alignment,
+            None if value.is_numeric() => Alignment::Right,
+            None => Alignment::Left,
+        }
+    }
+
+    // Without an explicit alignment, the header lines up with the cells below it: it
+    // is right-aligned over a column of numbers or one with a number format.
+    pub(crate) fn header_alignment<'a>(
+        &self,
+        values: impl Iterator<Item = &'a Value>,
+    ) -> Alignment {
+        match self.alignment {
+            Some(alignment) => alignment,
+            None if self.format.is_some() => Alignment::Right,
+            None => {
+                let mut filled = values.filter(|value| !value.is_empty()).peekable();
+                if filled.peek().is_some() && filled.all(Value::is_numeric) {
+                    Alignment::Right
+                } else {
+                    Alignment::Left
+                }
+            }
+        }
+    }
+
     pub(crate) fn get_content_width(&self, content_width: usize) -> usize {
         let width = content_width.max(self.min_width);
         match self.max_width {
@@ -52,9 +215,21 @@ impl Column {
         }
     }
 
-    pub(crate) fn format_content(&self, content: &str) -> String {
-        let width = self.get_content_width(content.len());
-        self.alignment.format_content(content, width)
+    // Splits a cell into the physical lines it occupies in a column `width` wide.
+    pub(crate) fn layout_content(&self, content: &str, width: usize) -> Vec<String> {
+        match self.overflow {
+            Overflow::Truncate => content.lines().map(str::to_string).collect(),
+            Overflow::Wrap => wrap_text(content, width),
+        }
+    }
+
+    pub(crate) fn format_content(
+        &self,
+        content: &str,
+        alignment: Alignment,
+        width: usize,
+    ) -> String {
+        alignment.format_content(content, width, &self.truncation)
     }
 
     pub(crate) fn total_width(&self) -> usize {
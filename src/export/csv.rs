@@ -0,0 +1,372 @@
+use super::{cell_text, header_label};
+use crate::{
+    column::Column,
+    table::Table,
+    value::{Date, Value},
+};
+use std::io::{self, Read, Write};
+
+/// Settings for reading and writing delimiter-separated values, see
+/// [`Table::to_csv`] and [`Table::from_csv`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    delimiter: char,
+    header: bool,
+    infer_types: bool,
+    formatted: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            header: true,
+            infer_types: true,
+            formatted: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Comma-separated values with a header row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tab-separated values with a header row.
+    pub fn tsv() -> Self {
+        Self::new().with_delimiter('\t')
+    }
+
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Whether the first line holds the column headers. Without it, imported
+    /// columns are named `Column 1`, `Column 2` and so on.
+    pub fn with_header(mut self, enabled: bool) -> Self {
+        self.header = enabled;
+        self
+    }
+
+    /// Whether imported fields that look like integers, floats, booleans or
+    /// `YYYY-MM-DD` dates become typed values instead of text. On by default.
+    pub fn with_type_inference(mut self, enabled: bool) -> Self {
+        self.infer_types = enabled;
+        self
+    }
+
+    /// Whether exported cells are written as the columns format them, instead of
+    /// their plain values. Off by default, so numbers read back unchanged.
+    pub fn with_formatted_values(mut self, enabled: bool) -> Self {
+        self.formatted = enabled;
+        self
+    }
+}
+
+pub(crate) fn write_csv(
+    table: &Table,
+    rows: &[usize],
+    columns: &[usize],
+    mut writer: impl Write,
+    options: &CsvOptions,
+) -> io::Result<()> {
+    let mut record = Vec::with_capacity(columns.len());
+    if options.header {
+        record.extend(columns.iter().map(|&i| header_label(table, i)));
+        write_record(&mut writer, &record, options.delimiter)?;
+    }
+
+    for &row in rows {
+        record.clear();
+        record.extend(columns.iter().map(|&i| {
+            if options.formatted {
+                cell_text(table, row, i)
+            } else {
+                table.rows[row]
+                    .cells
+                    .get(i)
+                    .map_or_else(String::new, Value::to_string)
+            }
+        }));
+        write_record(&mut writer, &record, options.delimiter)?;
+    }
+    writer.flush()
+}
+
+fn write_record(writer: &mut impl Write, fields: &[String], delimiter: char) -> io::Result<()> {
+    let mut line = String::new();
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            line.push(delimiter);
+        }
+        // Quoted as RFC 4180 requires, doubling any quotes inside.
+        if field.contains([delimiter, '"', '\n', '\r']) {
+            line.push('"');
+            line.push_str(&field.replace('"', "\"\""));
+            line.push('"');
+        } else {
+            line.push_str(field);
+        }
+    }
+    line.push('\n');
+    writer.write_all(line.as_bytes())
+}
+
+pub(crate) fn read_csv(mut reader: impl Read, options: &CsvOptions) -> io::Result<Table> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let mut records = parse_records(&input, options.delimiter)?.into_iter();
+
+    let headers = if options.header {
+        records.next().unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let records: Vec<Vec<String>> = records.collect();
+    let count = records.iter().map(Vec::len).fold(headers.len(), usize::max);
+
+    let mut builder = Table::builder();
+    for i in 0..count {
+        let header = match headers.get(i) {
+            Some(header) => header.clone(),
+            None => format!("Column {}", i + 1),
+        };
+        builder = builder.add_column(Column::new(header));
+    }
+
+    let mut table = builder.build();
+    table.add_rows(records.into_iter().map(|record| {
+        record
+            .into_iter()
+            .map(|field| {
+                if options.infer_types {
+                    infer(&field)
+                } else {
+                    Value::from(field)
+                }
+            })
+            .collect()
+    }));
+    Ok(table)
+}
+
+// Splits `input` into records of fields, following RFC 4180 and also accepting bare
+// `\n` line endings. Blank lines are skipped.
+fn parse_records(input: &str, delimiter: char) -> io::Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut chars = input.chars().peekable();
+    let mut line = 1;
+
+    loop {
+        let quoted = chars.peek() == Some(&'"');
+        if quoted {
+            chars.next();
+            let start = line;
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(ch) => {
+                        if ch == '\n' {
+                            line += 1;
+                        }
+                        field.push(ch);
+                    }
+                    None => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("unterminated quoted field starting on line {}", start),
+                        ))
+                    }
+                }
+            }
+        }
+
+        // Text after a closing quote is kept as part of the field.
+        let end = loop {
+            match chars.next() {
+                Some(ch) if ch == delimiter => break Some(ch),
+                Some('\r') if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                    break Some('\n');
+                }
+                Some(ch @ ('\n' | '\r')) => break Some(ch),
+                Some(ch) => field.push(ch),
+                None => break None,
+            }
+        };
+
+        record.push(std::mem::take(&mut field));
+        match end {
+            Some(ch) if ch == delimiter => continue,
+            _ => {
+                let blank = record.len() == 1 && record[0].is_empty() && !quoted;
+                if !blank {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+                line += 1;
+                if end.is_none() {
+                    return Ok(records);
+                }
+            }
+        }
+    }
+}
+
+// The typed value a field most likely stands for. Numbers with leading zeros, such
+// as postal codes, stay text.
+fn infer(field: &str) -> Value {
+    if field.is_empty() {
+        return Value::Empty;
+    }
+
+    let digits = field.strip_prefix(['-', '+']).unwrap_or(field);
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    let numeric = !digits.is_empty()
+        && digits.starts_with(|ch: char| ch.is_ascii_digit() || ch == '.')
+        && digits
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || matches!(ch, '.' | 'e' | 'E' | '-' | '+'));
+    if numeric && !leading_zero {
+        if let Ok(value) = field.parse::<i64>() {
+            return Value::Integer(value);
+        }
+        if let Ok(value) = field.parse::<f64>() {
+            return Value::Float(value);
+        }
+    }
+
+    if field.eq_ignore_ascii_case("true") {
+        return Value::Bool(true);
+    }
+    if field.eq_ignore_ascii_case("false") {
+        return Value::Bool(false);
+    }
+    parse_date(field).map_or_else(|| Value::from(field), Value::Date)
+}
+
+fn parse_date(field: &str) -> Option<Date> {
+    let bytes = field.as_bytes();
+    let shape = bytes.len() == 10 && bytes[4] == b'-' && bytes[7] == b'-';
+    if !shape {
+        return None;
+    }
+    Date::new(
+        field[0..4].parse().ok()?,
+        field[5..7].parse().ok()?,
+        field[8..10].parse().ok()?,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Vec<Vec<String>> {
+        parse_records(input, ',').unwrap()
+    }
+
+    #[test]
+    fn plain_records() {
+        assert_eq!(parse("a,b\n1,2\n"), [["a", "b"], ["1", "2"]]);
+        // The last line needs no line break, and blank lines are skipped.
+        assert_eq!(parse("a,b\n\n1,2"), [["a", "b"], ["1", "2"]]);
+        assert_eq!(parse(""), Vec::<Vec<String>>::new());
+        assert_eq!(parse("a,,c\n,\n"), [vec!["a", "", "c"], vec!["", ""]]);
+    }
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(parse("a,b\r\n1,2\r\n"), [["a", "b"], ["1", "2"]]);
+        assert_eq!(parse("a,b\r1,2\r"), [["a", "b"], ["1", "2"]]);
+        assert_eq!(parse("a\r\n\r\nb\n"), [["a"], ["b"]]);
+    }
+
+    #[test]
+    fn quoted_fields() {
+        assert_eq!(
+            parse("\"a,b\",\"say \"\"hi\"\"\"\n"),
+            [["a,b", "say \"hi\""]]
+        );
+        assert_eq!(
+            parse("\"two\nlines\",\"crlf\r\nkept\"\r\nnext,row\r\n"),
+            [["two\nlines", "crlf\r\nkept"], ["next", "row"]]
+        );
+        // A quoted empty field is a record, unlike a blank line.
+        assert_eq!(parse("\"\"\n"), [[""]]);
+        assert_eq!(parse("\"\""), [[""]]);
+        // Text after the closing quote stays part of the field.
+        assert_eq!(parse("\"ab\"c,d\n"), [["abc", "d"]]);
+        // Quotes inside an unquoted field are plain characters.
+        assert_eq!(parse("a\"b,c\n"), [["a\"b", "c"]]);
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        let error = parse_records("a,b\n1,\"open\n\nstill open", ',').unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.to_string(),
+            "unterminated quoted field starting on line 2"
+        );
+    }
+
+    #[test]
+    fn other_delimiters() {
+        assert_eq!(
+            parse_records("a\tb,c\n\"x\ty\"\tz\n", '\t').unwrap(),
+            [["a", "b,c"], ["x\ty", "z"]]
+        );
+        assert_eq!(parse_records("a;b\n", ';').unwrap(), [["a", "b"]]);
+    }
+
+    #[test]
+    fn inferred_types() {
+        assert_eq!(infer(""), Value::Empty);
+        assert_eq!(infer("42"), Value::Integer(42));
+        assert_eq!(infer("-7"), Value::Integer(-7));
+        assert_eq!(infer("+7"), Value::Integer(7));
+        assert_eq!(infer("0"), Value::Integer(0));
+        assert_eq!(infer("2.5"), Value::Float(2.5));
+        assert_eq!(infer("0.5"), Value::Float(0.5));
+        assert_eq!(infer(".5"), Value::Float(0.5));
+        assert_eq!(infer("1e3"), Value::Float(1000.0));
+        assert_eq!(infer("99999999999999999999"), Value::Float(1e20));
+        assert_eq!(infer("TRUE"), Value::Bool(true));
+        assert_eq!(infer("false"), Value::Bool(false));
+        assert_eq!(
+            infer("2024-02-29"),
+            Value::Date(Date::new(2024, 2, 29).unwrap())
+        );
+    }
+
+    #[test]
+    fn text_that_only_looks_typed() {
+        for text in [
+            "007",
+            "-01",
+            "1-2",
+            "-",
+            ".",
+            "1.2.3",
+            "inf",
+            "NaN",
+            "1,000",
+            " 5",
+            "yes",
+            "2023-02-29",
+            "2024-2-29",
+            "24-02-2029",
+        ] {
+            assert_eq!(infer(text), Value::from(text), "{:?}", text);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    alignment::Alignment,
+    render::{GridRow, Slot},
+    table::Table,
+};
+
+pub(crate) fn to_html(table: &Table, rows: &[usize], columns: &[usize]) -> String {
+    let (header, body, footer) = table.export_rows(columns, rows);
+
+    let mut out = String::from("<table class=\"pinax\">\n");
+    write_section(&mut out, "thead", "th", &header);
+    write_section(&mut out, "tbody", "td", &body);
+    if let Some(footer) = footer {
+        write_section(&mut out, "tfoot", "td", &[footer]);
+    }
+    out.push_str("</table>\n");
+    out
+}
+
+fn write_section(out: &mut String, section: &str, cell: &str, rows: &[GridRow]) {
+    out.push_str(&format!("  <{}>\n", section));
+    for row in rows {
+        out.push_str("    <tr>\n");
+        for slot in &row.slots {
+            let Slot::Anchor(anchor) = slot else {
+                continue;
+            };
+            let mut attributes = String::new();
+            let classes = anchor.view.style.class_names();
+            if !classes.is_empty() {
+                attributes.push_str(&format!(" class=\"{}\"", classes.join(" ")));
+            }
+            if anchor.colspan > 1 {
+                attributes.push_str(&format!(" colspan=\"{}\"", anchor.colspan));
+            }
+            if anchor.rowspan > 1 {
+                attributes.push_str(&format!(" rowspan=\"{}\"", anchor.rowspan));
+            }
+            let align = match anchor.view.alignment {
+                Alignment::Left => "left",
+                Alignment::Right => "right",
+                Alignment::Center => "center",
+            };
+            out.push_str(&format!(
+                "      <{cell}{} style=\"text-align: {}\">{}</{cell}>\n",
+                attributes,
+                align,
+                escape(&anchor.view.text),
+            ));
+        }
+        out.push_str("    </tr>\n");
+    }
+    out.push_str(&format!("  </{}>\n", section));
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.replace("\r\n", "\n").chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' | '\r' => escaped.push_str("<br>"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
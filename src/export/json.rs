@@ -0,0 +1,76 @@
+use crate::{table::Table, value::Value};
+use std::io::{self, Write};
+
+pub(crate) fn to_json(table: &Table, rows: &[usize], columns: &[usize]) -> String {
+    if rows.is_empty() {
+        return "[]\n".to_string();
+    }
+    let objects: Vec<String> = rows
+        .iter()
+        .map(|&row| format!("  {}", object(table, row, columns)))
+        .collect();
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+pub(crate) fn write_ndjson(
+    table: &Table,
+    rows: &[usize],
+    columns: &[usize],
+    mut writer: impl Write,
+) -> io::Result<()> {
+    for &row in rows {
+        writeln!(writer, "{}", object(table, row, columns))?;
+    }
+    writer.flush()
+}
+
+// One row as an object keyed by column header, on a single line.
+fn object(table: &Table, row: usize, columns: &[usize]) -> String {
+    let members: Vec<String> = columns
+        .iter()
+        .map(|&i| {
+            let value = table.rows[row].cells.get(i).unwrap_or(&Value::Empty);
+            format!("{}:{}", string(&table.columns[i].header), json_value(value))
+        })
+        .collect();
+    format!("{{{}}}", members.join(","))
+}
+
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Empty => "null".to_string(),
+        Value::Text(text) => string(text),
+        Value::Integer(value) => value.to_string(),
+        Value::Float(value) => number(*value),
+        Value::Bool(value) => value.to_string(),
+        Value::Date(date) => string(&date.to_string()),
+        Value::Duration(duration) => number(duration.as_secs_f64()),
+    }
+}
+
+// JSON has no NaN or infinity.
+fn number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+fn string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if ch.is_control() => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
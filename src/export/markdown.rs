@@ -0,0 +1,112 @@
+use super::{cell_text, header_label, is_section_row};
+use crate::{alignment::Alignment, table::Table, width::display_width};
+
+pub(crate) fn to_markdown(table: &Table, rows: &[usize], columns: &[usize]) -> String {
+    let header: Vec<String> = columns
+        .iter()
+        .map(|&i| escape(&header_label(table, i)))
+        .collect();
+
+    let mut body: Vec<Vec<String>> = rows
+        .iter()
+        .map(|&row| {
+            let section = is_section_row(table, row, columns);
+            columns
+                .iter()
+                .map(|&i| {
+                    let text = escape(&cell_text(table, row, i));
+                    if section {
+                        strong(text)
+                    } else {
+                        text
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    if table.has_footer(columns) {
+        body.push(
+            table
+                .footer_cells(columns, rows)
+                .into_iter()
+                .map(|cell| strong(escape(&cell.text)))
+                .collect(),
+        );
+    }
+
+    // Columns line up like their headers in the terminal, so numbers go right. Left
+    // is the Markdown default and only gets a colon when it was set explicitly.
+    let alignments: Vec<Option<Alignment>> = columns
+        .iter()
+        .map(|&i| {
+            let column = &table.columns[i];
+            let values = table.rows.iter().filter_map(|row| row.cells.get(i));
+            column.alignment.or(match column.header_alignment(values) {
+                Alignment::Left => None,
+                alignment => Some(alignment),
+            })
+        })
+        .collect();
+    // Delimiter rows need at least three dashes, colons included.
+    let widths: Vec<usize> = (0..columns.len())
+        .map(|j| {
+            body.iter()
+                .map(|cells| display_width(&cells[j]))
+                .fold(display_width(&header[j]), usize::max)
+                .max(3)
+        })
+        .collect();
+
+    let mut out = String::new();
+    write_row(&mut out, &header, &alignments, &widths);
+    let delimiters: Vec<String> = alignments
+        .iter()
+        .zip(&widths)
+        .map(|(alignment, &width)| delimiter(*alignment, width))
+        .collect();
+    out.push_str(&format!("| {} |\n", delimiters.join(" | ")));
+    for cells in &body {
+        write_row(&mut out, cells, &alignments, &widths);
+    }
+    out
+}
+
+fn write_row(
+    out: &mut String,
+    cells: &[String],
+    alignments: &[Option<Alignment>],
+    widths: &[usize],
+) {
+    let cells: Vec<String> = cells
+        .iter()
+        .zip(alignments)
+        .zip(widths)
+        .map(|((cell, alignment), &width)| alignment.unwrap_or(Alignment::Left).pad(cell, width))
+        .collect();
+    out.push_str(&format!("| {} |\n", cells.join(" | ")));
+}
+
+fn delimiter(alignment: Option<Alignment>, width: usize) -> String {
+    match alignment {
+        None => "-".repeat(width),
+        Some(Alignment::Left) => format!(":{}", "-".repeat(width - 1)),
+        Some(Alignment::Center) => format!(":{}:", "-".repeat(width - 2)),
+        Some(Alignment::Right) => format!("{}:", "-".repeat(width - 1)),
+    }
+}
+
+// Pipes would end the cell and line breaks the row.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+fn strong(text: String) -> String {
+    if text.is_empty() {
+        text
+    } else {
+        format!("**{}**", text)
+    }
+}
@@ -0,0 +1,45 @@
+// Text formats for pasting or loading tables elsewhere. Exports carry the formatted
+// cell text but no styles, and ignore the terminal width. CSV and JSON are the
+// exceptions and write plain values, so that programs can read them back.
+
+mod csv;
+mod html;
+mod json;
+mod markdown;
+
+pub use csv::CsvOptions;
+pub(crate) use csv::{read_csv, write_csv};
+pub(crate) use html::to_html;
+pub(crate) use json::{to_json, write_ndjson};
+pub(crate) use markdown::to_markdown;
+
+use crate::{table::Table, value::Value};
+
+// The header of column `index`, prefixed by the titles of its header groups since
+// most formats only have a single header row.
+fn header_label(table: &Table, index: usize) -> String {
+    let mut parts = table.group_titles(index);
+    parts.push(&table.columns[index].header);
+    parts.join(" / ")
+}
+
+// A cell as the column formats it.
+fn cell_text(table: &Table, row: usize, index: usize) -> String {
+    let value = table.rows[row].cells.get(index).unwrap_or(&Value::Empty);
+    table.columns[index].format_value(value)
+}
+
+// Whether `row` holds a single merged cell across all of `columns`, such as a
+// section title.
+fn is_section_row(table: &Table, row: usize, columns: &[usize]) -> bool {
+    let Some(&first) = columns.first() else {
+        return false;
+    };
+    table.rows[row].span_at(first).is_some_and(|span| {
+        columns.len() > 1
+            && span.rows == 1
+            && columns
+                .iter()
+                .all(|&i| i >= first && i < first.saturating_add(span.cols))
+    })
+}
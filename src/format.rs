@@ -0,0 +1,289 @@
+use crate::value::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Notation {
+    Plain,
+    Percent,
+    Si(String),
+    Binary(String),
+    Currency(String),
+}
+
+/// How a column renders its numeric cells.
+///
+/// Formats only apply to integer and float values; text, dates and other values are
+/// displayed unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Format {
+    notation: Notation,
+    decimals: Option<usize>,
+    thousands: Option<char>,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::number()
+    }
+}
+
+impl Format {
+    /// Plain numbers, displayed as they are unless decimals or a thousands separator
+    /// are set.
+    pub fn number() -> Self {
+        Self {
+            notation: Notation::Plain,
+            decimals: None,
+            thousands: None,
+        }
+    }
+
+    /// Fractions displayed as percentages, `0.25` becomes `25%`.
+    pub fn percent() -> Self {
+        Self {
+            notation: Notation::Percent,
+            ..Self::number()
+        }
+    }
+
+    /// Scales by powers of 1000 with SI prefixes, `1500` with unit `"B"` becomes
+    /// `1.5 kB`.
+    pub fn si(unit: impl Into<String>) -> Self {
+        Self {
+            notation: Notation::Si(unit.into()),
+            ..Self::number()
+        }
+    }
+
+    /// Scales by powers of 1024 with IEC prefixes, `1536` with unit `"B"` becomes
+    /// `1.5 KiB`.
+    pub fn binary(unit: impl Into<String>) -> Self {
+        Self {
+            notation: Notation::Binary(unit.into()),
+            ..Self::number()
+        }
+    }
+
+    /// Money with a leading currency symbol, two decimals and `,` thousands
+    /// separators unless overridden.
+    pub fn currency(symbol: impl Into<String>) -> Self {
+        Self {
+            notation: Notation::Currency(symbol.into()),
+            decimals: Some(2),
+            thousands: Some(','),
+        }
+    }
+
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    pub fn thousands(mut self, separator: char) -> Self {
+        self.thousands = Some(separator);
+        self
+    }
+
+    pub fn format(&self, value: &Value) -> String {
+        let number = match value {
+            Value::Integer(value) => *value as f64,
+            Value::Float(value) => *value,
+            other => return other.to_string(),
+        };
+
+        match &self.notation {
+            Notation::Plain => match (value, self.decimals) {
+                (Value::Integer(value), None) => self.group(&value.to_string()),
+                (_, None) => self.group(&number.to_string()),
+                (_, Some(decimals)) => self.fixed(number, decimals),
+            },
+            Notation::Percent => {
+                format!(
+                    "{}%",
+                    self.fixed(number * 100.0, self.decimals.unwrap_or(0))
+                )
+            }
+            Notation::Si(unit) => {
+                self.scaled(number, 1000.0, &["", "k", "M", "G", "T", "P", "E"], unit)
+            }
+            Notation::Binary(unit) => self.scaled(
+                number,
+                1024.0,
+                &["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei"],
+                unit,
+            ),
+            Notation::Currency(symbol) => {
+                let amount = self.fixed(number, self.decimals.unwrap_or(2));
+                match amount.strip_prefix('-') {
+                    Some(amount) => format!("-{}{}", symbol, amount),
+                    None => format!("{}{}", symbol, amount),
+                }
+            }
+        }
+    }
+
+    fn fixed(&self, number: f64, decimals: usize) -> String {
+        let fixed = format!("{:.*}", decimals, number);
+        // Numbers that round to zero lose their sign, `-0.001` is shown as `0.00`.
+        let fixed = match fixed.strip_prefix('-') {
+            Some(digits) if digits.chars().all(|ch| ch == '0' || ch == '.') => digits,
+            _ => &fixed,
+        };
+        self.group(fixed)
+    }
+
+    fn scaled(&self, number: f64, base: f64, prefixes: &[&str], unit: &str) -> String {
+        let mut scaled = number;
+        let mut prefix = 0;
+        loop {
+            while scaled.abs() >= base && prefix < prefixes.len() - 1 {
+                scaled /= base;
+                prefix += 1;
+            }
+
+            // Unscaled integers have no fractional part worth showing.
+            let decimals = match self.decimals {
+                Some(decimals) => decimals,
+                None if prefix == 0 && scaled.fract() == 0.0 => 0,
+                None => 1,
+            };
+            let amount = self.fixed(scaled, decimals);
+            // Rounding can reach the next prefix, `999_950` is `1.0 MB`, not `1000.0 kB`.
+            let rounded: f64 = format!("{:.*}", decimals, scaled).parse().unwrap_or(scaled);
+            if rounded.abs() >= base && prefix < prefixes.len() - 1 {
+                scaled = rounded;
+                continue;
+            }
+            return format!("{} {}{}", amount, prefixes[prefix], unit)
+                .trim_end()
+                .to_string();
+        }
+    }
+
+    // Inserts the thousands separator into the integer part of a formatted number.
+    fn group(&self, number: &str) -> String {
+        let Some(separator) = self.thousands else {
+            return number.to_string();
+        };
+
+        let (sign, digits) = number
+            .strip_prefix('-')
+            .map_or(("", number), |digits| ("-", digits));
+        let (integer, fraction) = digits
+            .find(|ch: char| !ch.is_ascii_digit())
+            .map_or((digits, ""), |idx| digits.split_at(idx));
+
+        let mut grouped = String::new();
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        format!("{}{}{}", sign, grouped, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(format: &Format, value: impl Into<Value>) -> String {
+        format.format(&value.into())
+    }
+
+    #[test]
+    fn plain() {
+        let plain = Format::number();
+        assert_eq!(format(&plain, 1234567), "1234567");
+        assert_eq!(format(&plain, 1.5), "1.5");
+        assert_eq!(format(&plain, -3), "-3");
+
+        let grouped = Format::number().thousands(',');
+        assert_eq!(format(&grouped, 1234567), "1,234,567");
+        assert_eq!(format(&grouped, -1234567), "-1,234,567");
+        assert_eq!(format(&grouped, 123), "123");
+        assert_eq!(format(&grouped, 1234.5), "1,234.5");
+
+        let fixed = Format::number().decimals(2).thousands('_');
+        assert_eq!(format(&fixed, 1234567.891), "1_234_567.89");
+        assert_eq!(format(&fixed, 7), "7.00");
+        assert_eq!(format(&Format::number().decimals(0), 2.5), "2");
+        assert_eq!(format(&Format::number().decimals(0), 3.5), "4");
+    }
+
+    #[test]
+    fn other_values_are_unchanged() {
+        let currency = Format::currency("$");
+        assert_eq!(format(&currency, "n/a"), "n/a");
+        assert_eq!(format(&currency, true), "true");
+        assert_eq!(format(&currency, Value::Empty), "");
+    }
+
+    #[test]
+    fn percent() {
+        assert_eq!(format(&Format::percent(), 0.25), "25%");
+        assert_eq!(format(&Format::percent(), 1), "100%");
+        assert_eq!(format(&Format::percent().decimals(1), 0.12345), "12.3%");
+        assert_eq!(format(&Format::percent().thousands(','), 12.5), "1,250%");
+        assert_eq!(format(&Format::percent(), -0.001), "0%");
+    }
+
+    #[test]
+    fn si() {
+        let si = Format::si("B");
+        assert_eq!(format(&si, 0), "0 B");
+        assert_eq!(format(&si, 999), "999 B");
+        assert_eq!(format(&si, 999.5), "999.5 B");
+        assert_eq!(format(&si, 1000), "1.0 kB");
+        assert_eq!(format(&si, 1500), "1.5 kB");
+        assert_eq!(format(&si, -2_500_000), "-2.5 MB");
+        assert_eq!(format(&si, 1e30), "1000000000000.0 EB");
+        assert_eq!(format(&Format::si("B").decimals(3), 1234), "1.234 kB");
+        assert_eq!(format(&Format::si(""), 1500), "1.5 k");
+        assert_eq!(format(&Format::si(""), 15), "15");
+    }
+
+    #[test]
+    fn rounding_moves_to_the_next_prefix() {
+        let si = Format::si("B");
+        assert_eq!(format(&si, 999.96), "1.0 kB");
+        assert_eq!(format(&si, 999_940), "999.9 kB");
+        assert_eq!(format(&si, 999_950), "1.0 MB");
+        assert_eq!(format(&si, -999_950), "-1.0 MB");
+        assert_eq!(format(&Format::si("B").decimals(0), 999_500), "1 MB");
+        assert_eq!(format(&Format::si("B").decimals(0), 999_499), "999 kB");
+        assert_eq!(format(&Format::binary("B"), 1_048_575), "1.0 MiB");
+    }
+
+    #[test]
+    fn binary() {
+        let binary = Format::binary("B");
+        assert_eq!(format(&binary, 1023), "1023 B");
+        assert_eq!(format(&binary, 1024), "1.0 KiB");
+        assert_eq!(format(&binary, 1536), "1.5 KiB");
+        assert_eq!(format(&binary, 3 * 1024 * 1024 * 1024_i64), "3.0 GiB");
+    }
+
+    #[test]
+    fn currency() {
+        let dollars = Format::currency("$");
+        assert_eq!(format(&dollars, 1234.5), "$1,234.50");
+        assert_eq!(format(&dollars, -1234.5), "-$1,234.50");
+        assert_eq!(format(&dollars, 0), "$0.00");
+        assert_eq!(format(&dollars, 0.005), "$0.01");
+        assert_eq!(format(&dollars, -0.001), "$0.00");
+        assert_eq!(format(&dollars, -0.0), "$0.00");
+        assert_eq!(format(&Format::currency("€").decimals(0), 1999.6), "€2,000");
+        assert_eq!(
+            format(&Format::currency("£").thousands(' '), 1234567),
+            "£1 234 567.00"
+        );
+    }
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!(format(&Format::number().decimals(2), -0.001), "0.00");
+        assert_eq!(format(&Format::number().decimals(2), -0.005), "-0.01");
+        assert_eq!(format(&Format::si("B"), -0.01), "0.0 B");
+    }
+}
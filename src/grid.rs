@@ -1,18 +1,27 @@
-use crate::border::BorderStyle;
+use crate::{
+    alignment::Alignment,
+    border::{BorderChars, BorderStyle, RuleKind},
+    separator::{RowLines, SeparatorPolicy},
+    theme::Theme,
+};
 use std::fmt;
 
+const CELL_WIDTH: usize = 20;
+
 pub struct Grid {
     cells: Vec<Vec<String>>,
     rows: usize,
     cols: usize,
-    style: BorderStyle,
+    chars: BorderChars,
+    separators: SeparatorPolicy,
 }
 
 #[derive(Default)]
 pub struct GridBuilder {
     rows: usize,
     cols: usize,
-    style: Option<BorderStyle>,
+    chars: Option<BorderChars>,
+    separators: Option<SeparatorPolicy>,
 }
 
 impl GridBuilder {
@@ -27,12 +36,33 @@ impl GridBuilder {
     }
 
     pub fn style(mut self, style: BorderStyle) -> Self {
-        self.style = Some(style);
+        self.chars = Some(style.get_chars());
+        self
+    }
+
+    /// Sets the border glyphs and separators from a preset, keeping a line between
+    /// every pair of rows. Separators set afterwards override those of the theme.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.chars = Some(theme.chars());
+        self.separators = Some(theme.separators().with_row_lines(RowLines::All));
+        self
+    }
+
+    /// Sets which lines are drawn between and around the cells. Grids are fully
+    /// ruled, as with [`SeparatorPolicy::ruled`], unless set otherwise.
+    pub fn separators(mut self, separators: SeparatorPolicy) -> Self {
+        self.separators = Some(separators);
         self
     }
 
     pub fn build(self) -> Grid {
-        Grid::new(self.rows, self.cols, self.style.unwrap_or_default())
+        Grid::new(
+            self.rows,
+            self.cols,
+            self.chars
+                .unwrap_or_else(|| BorderStyle::default().get_chars()),
+            self.separators.unwrap_or_else(SeparatorPolicy::ruled),
+        )
     }
 }
 
@@ -41,13 +71,14 @@ impl Grid {
         GridBuilder::new()
     }
 
-    fn new(rows: usize, cols: usize, style: BorderStyle) -> Self {
+    fn new(rows: usize, cols: usize, chars: BorderChars, separators: SeparatorPolicy) -> Self {
         let cells = vec![vec![String::new(); cols]; rows];
         Self {
             cells,
             rows,
             cols,
-            style,
+            chars,
+            separators,
         }
     }
 
@@ -57,64 +88,70 @@ impl Grid {
         }
     }
 
-    fn write_horizontal_border(
+    fn write_rule(
         &self,
         f: &mut fmt::Formatter<'_>,
-        left: char,
-        right: char,
-        horizontal: char,
-        separator: char,
+        chars: &BorderChars,
+        kind: RuleKind,
     ) -> fmt::Result {
-        write!(f, "{}", left)?;
+        let separators = &self.separators;
+        let column_lines = separators.column_lines;
+        let mut line = String::new();
+        if separators.frame {
+            line.push(chars.rule_left(kind, true));
+        }
         for i in 0..self.cols {
-            write!(f, "{}", horizontal.to_string().repeat(20))?;
-            if i < self.cols - 1 {
-                write!(f, "{}", separator)?;
+            line.push_str(&chars.rule_horizontal(kind).to_string().repeat(CELL_WIDTH));
+            if i + 1 < self.cols {
+                let up = column_lines && kind != RuleKind::Top;
+                let down = column_lines && kind != RuleKind::Bottom;
+                line.push(chars.rule_junction(kind, up, down, true, true));
             }
         }
-        writeln!(f, "{}", right)
+        if separators.frame {
+            line.push(chars.rule_right(kind, true));
+        }
+        writeln!(f, "{}", line)
     }
 }
 
 impl fmt::Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let chars = self.style.get_chars();
+        let chars = self.chars;
+        let separators = &self.separators;
+        let inner = if separators.column_lines {
+            chars.vertical
+        } else {
+            ' '
+        };
 
-        self.write_horizontal_border(
-            f,
-            chars.top_left,
-            chars.top_right,
-            chars.horizontal,
-            chars.top_separator,
-        )?;
+        if separators.outer_rules {
+            self.write_rule(f, &chars, RuleKind::Top)?;
+        }
 
         for (i, row) in self.cells.iter().enumerate() {
-            write!(f, "{}", chars.vertical)?;
+            if separators.row_lines.before(i) {
+                self.write_rule(f, &chars, RuleKind::Inner)?;
+            }
+
+            if separators.frame {
+                write!(f, "{}", chars.outer_vertical)?;
+            }
             for (j, cell) in row.iter().enumerate() {
-                write!(f, "{:^20}", cell)?;
-                if j < self.cols - 1 {
-                    write!(f, "{}", chars.vertical)?;
+                write!(f, "{}", Alignment::Center.pad(cell, CELL_WIDTH))?;
+                if j + 1 < self.cols {
+                    write!(f, "{}", inner)?;
                 }
             }
-            writeln!(f, "{}", chars.vertical)?;
-
-            if i < self.rows - 1 {
-                self.write_horizontal_border(
-                    f,
-                    chars.left_separator,
-                    chars.right_separator,
-                    chars.horizontal,
-                    chars.cross,
-                )?;
+            if separators.frame {
+                write!(f, "{}", chars.outer_vertical)?;
             }
+            writeln!(f)?;
         }
 
-        self.write_horizontal_border(
-            f,
-            chars.bottom_left,
-            chars.bottom_right,
-            chars.horizontal,
-            chars.bottom_separator,
-        )
+        if separators.outer_rules {
+            self.write_rule(f, &chars, RuleKind::Bottom)?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,122 @@
+use crate::{alignment::Alignment, column::Column, style::Style};
+
+enum HeaderChild {
+    Column(Box<Column>),
+    Group(HeaderGroup),
+}
+
+/// A title shown above the headers of several adjacent columns.
+///
+/// Groups nest, and each level of nesting adds a header row above the column
+/// headers. Columns outside any group, or in a shallower group, have their header
+/// stretched over the rows they are missing.
+pub struct HeaderGroup {
+    title: String,
+    alignment: Alignment,
+    style: Style,
+    children: Vec<HeaderChild>,
+}
+
+impl HeaderGroup {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            alignment: Alignment::Center,
+            style: Style::default(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn add_column(mut self, column: Column) -> Self {
+        self.children.push(HeaderChild::Column(Box::new(column)));
+        self
+    }
+
+    pub fn add_group(mut self, group: HeaderGroup) -> Self {
+        self.children.push(HeaderChild::Group(group));
+        self
+    }
+
+    /// Sets how the title is aligned over its columns, centered by default.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    // Moves the group's columns to the end of `columns`, recording where this group
+    // and the groups nested in it start and end.
+    pub(crate) fn flatten(
+        self,
+        depth: usize,
+        columns: &mut Vec<Column>,
+        spans: &mut Vec<GroupSpan>,
+    ) {
+        let start = columns.len();
+        let index = spans.len();
+        spans.push(GroupSpan {
+            title: self.title,
+            alignment: self.alignment,
+            style: self.style,
+            depth,
+            start,
+            end: start,
+        });
+
+        for child in self.children {
+            match child {
+                HeaderChild::Column(column) => columns.push(*column),
+                HeaderChild::Group(group) => group.flatten(depth + 1, columns, spans),
+            }
+        }
+        spans[index].end = columns.len();
+    }
+}
+
+/// A header group resolved to the range of table columns it covers.
+#[derive(Debug, Clone)]
+pub(crate) struct GroupSpan {
+    pub(crate) title: String,
+    pub(crate) alignment: Alignment,
+    pub(crate) style: Style,
+    /// Header row of the title, 0 being the topmost.
+    pub(crate) depth: usize,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl GroupSpan {
+    pub(crate) fn contains(&self, column: usize) -> bool {
+        (self.start..self.end).contains(&column)
+    }
+
+    // Follows the removal of column `index`, shrinking the group if it held it.
+    pub(crate) fn remove_column(&mut self, index: usize) {
+        if self.start > index {
+            self.start -= 1;
+        }
+        if self.end > index {
+            self.end -= 1;
+        }
+    }
+
+    // Makes room for a column inserted at `index`. It joins the group when it lands
+    // strictly inside, or also at either edge if it was a `member` before moving.
+    pub(crate) fn insert_column(&mut self, index: usize, member: bool) {
+        let joins = if member {
+            (self.start..=self.end).contains(&index)
+        } else {
+            self.start < index && index < self.end
+        };
+        if joins {
+            self.end += 1;
+        } else if index <= self.start {
+            self.start += 1;
+            self.end += 1;
+        }
+    }
+}
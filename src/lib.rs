@@ -1,19 +1,62 @@
+mod aggregate;
 mod alignment;
 mod border;
 mod chart;
 mod column;
+mod export;
+mod format;
 mod grid;
+mod header;
 mod panel;
+mod render;
+mod rule;
+mod separator;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sort;
+mod stream;
+mod style;
 mod table;
+mod tabular;
+mod terminal;
+mod theme;
+mod truncate;
+mod value;
+mod view;
+mod width;
+mod wrap;
 
-pub use alignment::Alignment;
-pub use border::BorderStyle;
+pub use aggregate::Aggregate;
+pub use alignment::{Alignment, VerticalAlignment};
+pub use border::{BorderChars, BorderStyle};
 pub use chart::{Chart, ChartType};
-pub use column::Column;
+pub use column::{Column, Overflow};
+pub use export::CsvOptions;
+pub use format::Format;
 pub use grid::{Grid, GridBuilder};
+pub use header::HeaderGroup;
 pub use panel::Panel;
-pub use table::{Table, TableBuilder};
+pub use rule::{Condition, RowRule, Rule};
+pub use separator::{RowLines, SeparatorPolicy};
+#[cfg(feature = "serde")]
+pub use serialize::SerializeError;
+pub use sort::{ColumnKey, Comparator, SortKey, SortOrder};
+pub use stream::{StreamingTable, StreamingTableBuilder};
+pub use style::{color_enabled, set_color_choice, Color, ColorChoice, Style};
+pub use table::{RowMut, Table, TableBuilder};
+pub use tabular::Tabular;
+#[cfg(feature = "derive")]
+pub use pinax_derive::Tabular;
+pub use terminal::terminal_width;
+pub use theme::Theme;
+pub use truncate::{Ellipsis, TruncatePosition, Truncation};
+pub use value::{Date, Value};
+pub use view::{GroupedView, TableView};
+pub use width::{char_width, display_width};
 
 pub mod prelude {
-    pub use super::{Alignment, BorderStyle, Chart, ChartType, Column, Grid, Panel, Table};
+    pub use super::{
+        Aggregate, Alignment, BorderStyle, Chart, ChartType, Column, Format, Grid, HeaderGroup,
+        Panel, Table, Tabular, Theme, Value,
+    };
 }
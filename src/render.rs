@@ -0,0 +1,489 @@
+use crate::{
+    alignment::Alignment,
+    border::{BorderChars, RuleKind},
+    column::Column,
+    separator::SeparatorPolicy,
+    style::Style,
+    width::max_line_width,
+};
+use std::fmt;
+
+/// A cell after formatting and rule evaluation, ready to be laid out.
+pub(crate) struct CellView {
+    pub(crate) text: String,
+    pub(crate) alignment: Alignment,
+    pub(crate) style: Style,
+}
+
+/// The top-left cell of a (possibly merged) area of the grid.
+pub(crate) struct Anchor {
+    pub(crate) view: CellView,
+    /// Index of the table column whose settings lay out the content.
+    pub(crate) column: usize,
+    pub(crate) rowspan: usize,
+    pub(crate) colspan: usize,
+}
+
+pub(crate) enum Slot {
+    Anchor(Anchor),
+    /// Part of the merged cell anchored at the given grid row and position.
+    Covered {
+        row: usize,
+        col: usize,
+    },
+}
+
+pub(crate) struct GridRow {
+    /// One slot per visible column.
+    pub(crate) slots: Vec<Slot>,
+    /// Whether a horizontal rule separates this row from the previous one.
+    pub(crate) rule_above: bool,
+}
+
+impl GridRow {
+    /// A row of unmerged cells, one per visible column.
+    pub(crate) fn single(
+        cells: impl IntoIterator<Item = (usize, CellView)>,
+        rule_above: bool,
+    ) -> Self {
+        Self {
+            slots: cells
+                .into_iter()
+                .map(|(column, view)| {
+                    Slot::Anchor(Anchor {
+                        view,
+                        column,
+                        rowspan: 1,
+                        colspan: 1,
+                    })
+                })
+                .collect(),
+            rule_above,
+        }
+    }
+}
+
+/// Grid rows under construction, filled in one anchor at a time.
+pub(crate) struct SlotGrid {
+    slots: Vec<Vec<Option<Slot>>>,
+    /// Index of the first of these rows in the whole grid.
+    base: usize,
+}
+
+impl SlotGrid {
+    pub(crate) fn new(rows: usize, columns: usize, base: usize) -> Self {
+        Self {
+            slots: (0..rows)
+                .map(|_| (0..columns).map(|_| None).collect())
+                .collect(),
+            base,
+        }
+    }
+
+    pub(crate) fn is_free(&self, p: usize, j: usize) -> bool {
+        self.slots[p][j].is_none()
+    }
+
+    /// Places `anchor` at (`p`, `j`) and marks the rest of its area as covered.
+    pub(crate) fn place(&mut self, p: usize, j: usize, anchor: Anchor) {
+        for covered in &mut self.slots[p..p + anchor.rowspan] {
+            for slot in &mut covered[j..j + anchor.colspan] {
+                *slot = Some(Slot::Covered {
+                    row: self.base + p,
+                    col: j,
+                });
+            }
+        }
+        self.slots[p][j] = Some(Slot::Anchor(anchor));
+    }
+
+    pub(crate) fn into_rows(self, rule_above: impl Fn(usize) -> bool) -> Vec<GridRow> {
+        self.slots
+            .into_iter()
+            .enumerate()
+            .map(|(p, slots)| GridRow {
+                slots: slots.into_iter().flatten().collect(),
+                rule_above: rule_above(p),
+            })
+            .collect()
+    }
+}
+
+/// Draws rows of cells, some of which may span several rows or columns, and picks
+/// the junction glyph for every point where border lines meet.
+pub(crate) struct GridRenderer<'a> {
+    pub(crate) columns: &'a [Column],
+    pub(crate) chars: BorderChars,
+    pub(crate) border_color: Style,
+    pub(crate) separators: SeparatorPolicy,
+    /// Number of leading rows that make up the header, so the line below them can
+    /// be drawn with the header glyphs.
+    pub(crate) header_rows: usize,
+}
+
+impl GridRenderer<'_> {
+    /// Renders `rows` with the given widths per visible column. With `grow`, columns
+    /// are widened so merged cells and group titles fit their content.
+    pub(crate) fn render(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        rows: &[GridRow],
+        widths: &[usize],
+        grow: bool,
+    ) -> fmt::Result {
+        self.render_rows(f, rows, widths, grow, 0, true)
+    }
+
+    /// Renders the rows from `first` on, for tables written a few rows at a time.
+    /// Earlier rows are not drawn again and only decide the line above `first`. The
+    /// top line is drawn when starting from the first row, the bottom line only when
+    /// `close` is set.
+    pub(crate) fn render_rows(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        rows: &[GridRow],
+        widths: &[usize],
+        grow: bool,
+        first: usize,
+        close: bool,
+    ) -> fmt::Result {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut widths = widths.to_vec();
+        if grow {
+            self.grow_for_spans(rows, &mut widths);
+        }
+        let grid = Grid::new(self, rows, widths);
+
+        let outer_rules = self.separators.outer_rules;
+        if outer_rules && first == 0 {
+            self.write_rule(f, &grid, None, Some(0))?;
+        }
+        for (p, row) in rows.iter().enumerate().skip(first) {
+            if p > 0 && row.rule_above {
+                self.write_rule(f, &grid, Some(p - 1), Some(p))?;
+            }
+            for line in 0..grid.heights[p] {
+                self.write_content(f, &grid, p, grid.starts[p] + line)?;
+            }
+        }
+        if outer_rules && close {
+            self.write_rule(f, &grid, Some(rows.len() - 1), None)?;
+        }
+        Ok(())
+    }
+
+    fn grow_for_spans(&self, rows: &[GridRow], widths: &mut [usize]) {
+        for row in rows {
+            for (j, slot) in row.slots.iter().enumerate() {
+                let Slot::Anchor(anchor) = slot else {
+                    continue;
+                };
+                let col = &self.columns[anchor.column];
+                let content = max_line_width(&anchor.view.text);
+                let needed = col.max_width.map_or(content, |max| content.min(max)) + col.padding;
+                widen(&mut widths[j..j + anchor.colspan], needed);
+            }
+        }
+    }
+
+    // Draws the horizontal line between rows `above` and `below`; `None` stands for
+    // the outside of the table.
+    fn write_rule(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        grid: &Grid<'_>,
+        above: Option<usize>,
+        below: Option<usize>,
+    ) -> fmt::Result {
+        let n = grid.widths.len();
+        let up = |k: usize| above.is_some_and(|p| grid.has_vertical(p, k));
+        let down = |k: usize| below.is_some_and(|p| grid.has_vertical(p, k));
+        // Segments under a cell that spans across this rule show its content instead.
+        let crossing = |j: usize| below.filter(|&p| grid.crosses_from_above(p, j));
+        let segment = |j: usize| j < n && crossing(j).is_none();
+
+        let frame = self.separators.frame;
+        let kind = match (above, below) {
+            (None, _) => RuleKind::Top,
+            (_, None) => RuleKind::Bottom,
+            (Some(p), Some(_)) if p + 1 == self.header_rows => RuleKind::Header,
+            _ => RuleKind::Inner,
+        };
+        let horizontal = self.chars.rule_horizontal(kind);
+        // Without columns, the frame still closes, as `├┤` for inner rules.
+        let through = |j: usize| n == 0 || segment(j);
+        let mut line = LineWriter::new(self.border_color);
+        if frame {
+            line.border(self.chars.rule_left(kind, through(0)));
+        }
+
+        let mut j = 0;
+        while j < n {
+            if let Some(p) = crossing(j) {
+                let (ap, aj) = grid.resolve(p, j);
+                let line_index = grid.starts[p] - 1 - grid.starts[ap];
+                line.cell(&grid.segment(ap, aj, line_index));
+                j = aj + grid.anchor(ap, aj).colspan;
+            } else {
+                line.border_run(horizontal, grid.widths[j]);
+                j += 1;
+            }
+            if j < n {
+                line.border(self.chars.rule_junction(
+                    kind,
+                    up(j),
+                    down(j),
+                    segment(j - 1),
+                    segment(j),
+                ));
+            }
+        }
+        if frame {
+            line.border(self.chars.rule_right(kind, through(n.saturating_sub(1))));
+        }
+
+        writeln!(f, "{}", line.finish())
+    }
+
+    fn write_content(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        grid: &Grid<'_>,
+        p: usize,
+        global_line: usize,
+    ) -> fmt::Result {
+        let n = grid.widths.len();
+        let frame = self.separators.frame;
+        let inner = if self.separators.column_lines {
+            self.chars.vertical
+        } else {
+            ' '
+        };
+        let mut line = LineWriter::new(self.border_color);
+        if frame {
+            line.border(self.chars.outer_vertical);
+        }
+
+        let mut j = 0;
+        while j < n {
+            let (ap, aj) = grid.resolve(p, j);
+            line.cell(&grid.segment(ap, aj, global_line - grid.starts[ap]));
+            j = aj + grid.anchor(ap, aj).colspan;
+            if j < n {
+                line.border(inner);
+            }
+        }
+        if frame {
+            line.border(self.chars.outer_vertical);
+        }
+
+        writeln!(f, "{}", line.finish())
+    }
+}
+
+/// Grid rows with their content laid out and their heights resolved.
+struct Grid<'a> {
+    renderer: &'a GridRenderer<'a>,
+    rows: &'a [GridRow],
+    widths: Vec<usize>,
+    /// Wrapped lines of every anchor, empty for covered slots.
+    lines: Vec<Vec<Vec<String>>>,
+    heights: Vec<usize>,
+    /// Index of the first content line of each row, counting rules between rows.
+    starts: Vec<usize>,
+}
+
+impl<'a> Grid<'a> {
+    fn new(renderer: &'a GridRenderer<'a>, rows: &'a [GridRow], widths: Vec<usize>) -> Self {
+        let lines: Vec<Vec<Vec<String>>> = rows
+            .iter()
+            .map(|row| {
+                row.slots
+                    .iter()
+                    .enumerate()
+                    .map(|(j, slot)| match slot {
+                        Slot::Anchor(anchor) => {
+                            let col = &renderer.columns[anchor.column];
+                            let width = span_width(&widths, j, anchor.colspan);
+                            col.layout_content(&anchor.view.text, width - col.padding)
+                        }
+                        Slot::Covered { .. } => Vec::new(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut heights: Vec<usize> = rows
+            .iter()
+            .zip(&lines)
+            .map(|(row, lines)| {
+                row.slots
+                    .iter()
+                    .zip(lines)
+                    .filter(|(slot, _)| matches!(slot, Slot::Anchor(a) if a.rowspan == 1))
+                    .map(|(_, lines)| lines.len())
+                    .max()
+                    .unwrap_or(0)
+                    .max(1)
+            })
+            .collect();
+
+        // Cells spanning several rows stretch the last row they cover if the rows
+        // are not tall enough already. Blocks ending earlier are settled first.
+        let mut blocks: Vec<(usize, usize, usize)> = Vec::new();
+        for (p, row) in rows.iter().enumerate() {
+            for (j, slot) in row.slots.iter().enumerate() {
+                if let Slot::Anchor(anchor) = slot {
+                    if anchor.rowspan > 1 {
+                        blocks.push((p + anchor.rowspan - 1, p, lines[p][j].len()));
+                    }
+                }
+            }
+        }
+        blocks.sort_unstable();
+        for (last, first, needed) in blocks {
+            let available = heights[first..=last].iter().sum::<usize>()
+                + rows[first + 1..=last]
+                    .iter()
+                    .filter(|row| row.rule_above)
+                    .count();
+            if needed > available {
+                heights[last] += needed - available;
+            }
+        }
+
+        let mut starts = Vec::with_capacity(rows.len());
+        let mut line = 0;
+        for (p, row) in rows.iter().enumerate() {
+            if p > 0 && row.rule_above {
+                line += 1;
+            }
+            starts.push(line);
+            line += heights[p];
+        }
+
+        Self {
+            renderer,
+            rows,
+            widths,
+            lines,
+            heights,
+            starts,
+        }
+    }
+
+    fn resolve(&self, p: usize, j: usize) -> (usize, usize) {
+        match self.rows[p].slots[j] {
+            Slot::Anchor(_) => (p, j),
+            Slot::Covered { row, col } => (row, col),
+        }
+    }
+
+    fn anchor(&self, p: usize, j: usize) -> &Anchor {
+        match &self.rows[p].slots[j] {
+            Slot::Anchor(anchor) => anchor,
+            Slot::Covered { .. } => unreachable!("covered slots are resolved first"),
+        }
+    }
+
+    // Whether a vertical line runs through row `p` at boundary `k`, where boundary
+    // `k` is to the left of visible column `k`.
+    fn has_vertical(&self, p: usize, k: usize) -> bool {
+        let separators = &self.renderer.separators;
+        if k == 0 || k == self.widths.len() {
+            separators.frame
+        } else {
+            separators.column_lines && self.resolve(p, k - 1) != self.resolve(p, k)
+        }
+    }
+
+    fn crosses_from_above(&self, p: usize, j: usize) -> bool {
+        matches!(self.rows[p].slots[j], Slot::Covered { row, .. } if row < p)
+    }
+
+    // One line of the anchor at (`p`, `j`), padded to its full width and styled.
+    fn segment(&self, p: usize, j: usize, line_index: usize) -> String {
+        let anchor = self.anchor(p, j);
+        let lines = &self.lines[p][j];
+        let col = &self.renderer.columns[anchor.column];
+        let width = span_width(&self.widths, j, anchor.colspan);
+        let last = p + anchor.rowspan - 1;
+        let block_height = self.starts[last] + self.heights[last] - self.starts[p];
+
+        let offset = col.vertical_alignment.offset(lines.len(), block_height);
+        let content = line_index
+            .checked_sub(offset)
+            .and_then(|idx| lines.get(idx))
+            .map(String::as_str)
+            .unwrap_or_default();
+        let formatted = col.format_content(content, anchor.view.alignment, width - col.padding);
+        anchor
+            .view
+            .style
+            .paint(&Alignment::Left.pad(&formatted, width))
+    }
+}
+
+/// Spreads any width `needed` beyond what the adjacent `widths` offer, including the
+/// borders between them, evenly over those columns.
+pub(crate) fn widen(widths: &mut [usize], needed: usize) {
+    let available = span_width(widths, 0, widths.len());
+    if needed > available {
+        let extra = needed - available;
+        let share = extra / widths.len();
+        for width in widths.iter_mut() {
+            *width += share;
+        }
+        widths[widths.len() - 1] += extra - share * widths.len();
+    }
+}
+
+// Width of `colspan` columns starting at `start`, including the borders between them.
+fn span_width(widths: &[usize], start: usize, colspan: usize) -> usize {
+    widths[start..start + colspan].iter().sum::<usize>() + colspan - 1
+}
+
+/// Builds one output line, painting runs of border glyphs with the border style.
+struct LineWriter {
+    style: Style,
+    out: String,
+    border: String,
+}
+
+impl LineWriter {
+    fn new(style: Style) -> Self {
+        Self {
+            style,
+            out: String::new(),
+            border: String::new(),
+        }
+    }
+
+    fn border(&mut self, glyph: char) {
+        self.border.push(glyph);
+    }
+
+    fn border_run(&mut self, glyph: char, count: usize) {
+        self.border.extend(std::iter::repeat_n(glyph, count));
+    }
+
+    fn cell(&mut self, content: &str) {
+        self.flush();
+        self.out.push_str(content);
+    }
+
+    fn flush(&mut self) {
+        if !self.border.is_empty() {
+            self.out.push_str(&self.style.paint(&self.border));
+            self.border.clear();
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush();
+        self.out
+    }
+}
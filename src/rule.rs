@@ -0,0 +1,140 @@
+use crate::{style::Style, value::Value};
+use std::sync::Arc;
+
+/// A test applied to a cell when the table is rendered.
+///
+/// Numeric conditions only match numbers and text that parses as a number. Text
+/// conditions see the cell as it is displayed, after the column's format.
+#[derive(Clone)]
+pub enum Condition {
+    LessThan(f64),
+    GreaterThan(f64),
+    /// Inclusive on both ends.
+    Between(f64, f64),
+    Equals(String),
+    Contains(String),
+    /// The cell is among the largest `n` percent of the numeric values in its column.
+    TopPercent(f64),
+    /// The cell is among the smallest `n` percent of the numeric values in its column.
+    BottomPercent(f64),
+    #[cfg(feature = "regex")]
+    Matches(regex::Regex),
+    Custom(Arc<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+impl Condition {
+    /// Builds a [`Condition::Matches`] from a regular expression pattern.
+    #[cfg(feature = "regex")]
+    pub fn matches(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Self::Matches)
+    }
+
+    pub fn custom(predicate: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(predicate))
+    }
+
+    fn needs_stats(&self) -> bool {
+        matches!(self, Self::TopPercent(_) | Self::BottomPercent(_))
+    }
+
+    fn is_met(&self, value: &Value, text: &str, stats: &ColumnStats) -> bool {
+        let number = value.as_f64();
+        match self {
+            Self::LessThan(limit) => number.is_some_and(|n| n < *limit),
+            Self::GreaterThan(limit) => number.is_some_and(|n| n > *limit),
+            Self::Between(low, high) => number.is_some_and(|n| n >= *low && n <= *high),
+            Self::Equals(expected) => text == expected,
+            Self::Contains(needle) => text.contains(needle.as_str()),
+            Self::TopPercent(percent) => number
+                .zip(stats.top_threshold(*percent))
+                .is_some_and(|(n, threshold)| n >= threshold),
+            Self::BottomPercent(percent) => number
+                .zip(stats.bottom_threshold(*percent))
+                .is_some_and(|(n, threshold)| n <= threshold),
+            #[cfg(feature = "regex")]
+            Self::Matches(regex) => regex.is_match(text),
+            Self::Custom(predicate) => predicate(text),
+        }
+    }
+}
+
+/// Highlights the cells of a column that meet a [`Condition`].
+#[derive(Clone)]
+pub struct Rule {
+    condition: Condition,
+    style: Style,
+}
+
+impl Rule {
+    pub fn new(condition: Condition, style: Style) -> Self {
+        Self { condition, style }
+    }
+
+    pub(crate) fn needs_stats(&self) -> bool {
+        self.condition.needs_stats()
+    }
+
+    pub(crate) fn style_for(
+        &self,
+        value: &Value,
+        text: &str,
+        stats: &ColumnStats,
+    ) -> Option<Style> {
+        self.condition
+            .is_met(value, text, stats)
+            .then_some(self.style)
+    }
+}
+
+type RowPredicate = dyn Fn(&[Value]) -> bool + Send + Sync;
+
+/// Highlights whole rows whose cells satisfy a predicate.
+#[derive(Clone)]
+pub struct RowRule {
+    predicate: Arc<RowPredicate>,
+    style: Style,
+}
+
+impl RowRule {
+    pub fn new(predicate: impl Fn(&[Value]) -> bool + Send + Sync + 'static, style: Style) -> Self {
+        Self {
+            predicate: Arc::new(predicate),
+            style,
+        }
+    }
+
+    pub(crate) fn style_for(&self, row: &[Value]) -> Option<Style> {
+        (self.predicate)(row).then_some(self.style)
+    }
+}
+
+/// Numeric values of a column, sorted ascending, for percentile conditions.
+#[derive(Default)]
+pub(crate) struct ColumnStats {
+    values: Vec<f64>,
+}
+
+impl ColumnStats {
+    pub(crate) fn new<'a>(cells: impl Iterator<Item = &'a Value>) -> Self {
+        let mut values: Vec<f64> = cells.filter_map(Value::as_f64).collect();
+        values.sort_by(f64::total_cmp);
+        Self { values }
+    }
+
+    fn rank(&self, percent: f64) -> Option<usize> {
+        if self.values.is_empty() || percent <= 0.0 {
+            return None;
+        }
+        let count = (self.values.len() as f64 * percent / 100.0).ceil() as usize;
+        Some(count.clamp(1, self.values.len()))
+    }
+
+    fn top_threshold(&self, percent: f64) -> Option<f64> {
+        self.rank(percent)
+            .map(|count| self.values[self.values.len() - count])
+    }
+
+    fn bottom_threshold(&self, percent: f64) -> Option<f64> {
+        self.rank(percent).map(|count| self.values[count - 1])
+    }
+}
@@ -0,0 +1,104 @@
+/// Which horizontal lines are drawn between data rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RowLines {
+    #[default]
+    None,
+    All,
+    /// A line after every `n` rows, to help the eye follow long tables.
+    Every(usize),
+}
+
+impl RowLines {
+    // Whether a line is drawn above the row at `index`, counted from the first row.
+    pub(crate) fn before(&self, index: usize) -> bool {
+        match self {
+            _ if index == 0 => false,
+            Self::None => false,
+            Self::All => true,
+            Self::Every(n) => index.is_multiple_of(*n),
+        }
+    }
+}
+
+/// Which lines a [`Table`](crate::Table) or [`Grid`](crate::Grid) draws besides
+/// the cells themselves.
+///
+/// Columns without a line between them are still kept one space apart. Lines around
+/// header groups, group titles, subtotals and the footer are always drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeparatorPolicy {
+    pub(crate) row_lines: RowLines,
+    pub(crate) column_lines: bool,
+    /// The lines on the left and right of the table.
+    pub(crate) frame: bool,
+    /// The lines above and below the table.
+    pub(crate) outer_rules: bool,
+    pub(crate) header_line: bool,
+}
+
+impl Default for SeparatorPolicy {
+    /// A frame, lines between columns and a line under the header, but none between
+    /// data rows.
+    fn default() -> Self {
+        Self {
+            row_lines: RowLines::None,
+            column_lines: true,
+            frame: true,
+            outer_rules: true,
+            header_line: true,
+        }
+    }
+}
+
+impl SeparatorPolicy {
+    /// Every line, including one between each pair of data rows.
+    pub fn ruled() -> Self {
+        Self {
+            row_lines: RowLines::All,
+            ..Self::default()
+        }
+    }
+
+    /// No lines at all, only columns of aligned text like `ls -l`.
+    pub fn compact() -> Self {
+        Self {
+            row_lines: RowLines::None,
+            column_lines: false,
+            frame: false,
+            outer_rules: false,
+            header_line: false,
+        }
+    }
+
+    pub fn with_row_lines(mut self, row_lines: RowLines) -> Self {
+        self.row_lines = row_lines;
+        self
+    }
+
+    /// Turns the vertical lines between columns on or off.
+    pub fn with_column_lines(mut self, enabled: bool) -> Self {
+        self.column_lines = enabled;
+        self
+    }
+
+    /// Turns the border around the whole table on or off.
+    pub fn with_frame(mut self, enabled: bool) -> Self {
+        self.frame = enabled;
+        self.outer_rules = enabled;
+        self
+    }
+
+    /// Turns only the top and bottom lines of the border on or off, keeping the
+    /// sides as set by [`with_frame`](Self::with_frame).
+    pub fn with_outer_rules(mut self, enabled: bool) -> Self {
+        self.outer_rules = enabled;
+        self
+    }
+
+    /// Turns the line between the header and the first data row on or off. Grids
+    /// have no header and ignore it.
+    pub fn with_header_line(mut self, enabled: bool) -> Self {
+        self.header_line = enabled;
+        self
+    }
+}
@@ -0,0 +1,557 @@
+// Conversions between tables and serde, behind the `serde` feature. Rows are
+// flattened by a serializer that records every scalar under its dotted field path.
+
+use crate::{column::Column, table::Table, value::Value, view::TableView};
+use serde::ser::{self, Serialize, SerializeMap, SerializeSeq, Serializer};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+/// The error returned by [`Table::from_serialize`] when a row fails to serialize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializeError(String);
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl Table {
+    /// Builds a table from a slice of serializable rows, typically structs.
+    ///
+    /// Each field becomes a [`Column`] named after it, in the order fields are first
+    /// seen. Nested structs and maps are flattened into dotted names such as
+    /// `address.city`, tuples into `pos.0`, `pos.1` and so on, and enum variants
+    /// into `field.Variant`. Numbers, booleans and strings become typed cells,
+    /// `None` and unit values empty cells, and sequences a single cell listing
+    /// their elements. Fields missing from some rows are left empty there, and an
+    /// optional struct that is `None` in some rows only adds its nested columns.
+    ///
+    /// ```text
+    /// #[derive(Serialize)]
+    /// struct Prediction { height: f64, weight: f64, predicted: String }
+    ///
+    /// let table = Table::from_serialize(&predictions)?;
+    /// ```
+    pub fn from_serialize<T: Serialize>(rows: &[T]) -> Result<Table, SerializeError> {
+        let mut records = Vec::with_capacity(rows.len());
+        let mut headers: Vec<String> = Vec::new();
+        let mut seen: HashSet<String> = HashSet::new();
+        // Headers that so far only held `None` or unit values, and may turn out to
+        // be a nested struct that is missing from the first rows.
+        let mut unfilled: HashSet<String> = HashSet::new();
+
+        for row in rows {
+            let mut cells = Vec::new();
+            row.serialize(Cells::new(String::new(), &mut cells))?;
+            for (key, value) in &mut cells {
+                if key.is_empty() {
+                    *key = "value".to_string();
+                }
+                let filled = !value.is_empty();
+                if seen.contains(key.as_str()) {
+                    if filled {
+                        unfilled.remove(key.as_str());
+                    }
+                    continue;
+                }
+
+                // Fields nested under an unfilled header take its place.
+                let parent = headers
+                    .iter()
+                    .position(|header| unfilled.contains(header) && is_nested(key, header));
+                match parent {
+                    Some(position) => headers.insert(position, key.clone()),
+                    None => headers.push(key.clone()),
+                }
+                seen.insert(key.clone());
+                if !filled {
+                    unfilled.insert(key.clone());
+                }
+            }
+            records.push(cells);
+        }
+
+        let replaced: HashSet<String> = unfilled
+            .into_iter()
+            .filter(|header| headers.iter().any(|key| is_nested(key, header)))
+            .collect();
+        headers.retain(|header| !replaced.contains(header));
+        let positions: HashMap<&str, usize> = headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| (header.as_str(), i))
+            .collect();
+        let records: Vec<Vec<Value>> = records
+            .into_iter()
+            .map(|cells| {
+                let mut record = vec![Value::Empty; headers.len()];
+                for (key, value) in cells {
+                    if let Some(&position) = positions.get(key.as_str()) {
+                        record[position] = value;
+                    }
+                }
+                record
+            })
+            .collect();
+
+        let mut table = headers
+            .into_iter()
+            .fold(Table::builder(), |builder, header| {
+                builder.add_column(Column::new(header))
+            })
+            .build();
+        table.add_rows(records);
+        Ok(table)
+    }
+}
+
+// Whether `key` names a field nested somewhere under `parent`, e.g. `addr.city`
+// under `addr`.
+fn is_nested(key: &str, parent: &str) -> bool {
+    key.strip_prefix(parent)
+        .is_some_and(|rest| rest.starts_with('.'))
+}
+
+/// Tables serialize as a sequence of maps from column header to cell, like
+/// [`Table::to_json`].
+impl Serialize for Table {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.view().serialize(serializer)
+    }
+}
+
+/// Views serialize their rows and columns like [`Table`].
+impl Serialize for TableView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.rows.len()))?;
+        for &row in &self.rows {
+            seq.serialize_element(&RowRef {
+                table: self.table,
+                row,
+                columns: &self.columns,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct RowRef<'a> {
+    table: &'a Table,
+    row: usize,
+    columns: &'a [usize],
+}
+
+impl Serialize for RowRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for &i in self.columns {
+            let value = self.table.rows[self.row]
+                .cells
+                .get(i)
+                .unwrap_or(&Value::Empty);
+            map.serialize_entry(&self.table.columns[i].header, value)?;
+        }
+        map.end()
+    }
+}
+
+/// Cells serialize as their plain value: durations as seconds and dates as
+/// `YYYY-MM-DD` strings.
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Empty => serializer.serialize_none(),
+            Self::Text(text) => serializer.serialize_str(text),
+            Self::Integer(value) => serializer.serialize_i64(*value),
+            Self::Float(value) => serializer.serialize_f64(*value),
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Date(date) => serializer.collect_str(date),
+            Self::Duration(duration) => serializer.serialize_f64(duration.as_secs_f64()),
+        }
+    }
+}
+
+fn join(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", prefix, name)
+    }
+}
+
+// Records the scalars of one value under `prefix`, descending into compound values.
+struct Cells<'a> {
+    prefix: String,
+    out: &'a mut Vec<(String, Value)>,
+}
+
+impl<'a> Cells<'a> {
+    fn new(prefix: String, out: &'a mut Vec<(String, Value)>) -> Self {
+        Self { prefix, out }
+    }
+
+    fn push(self, value: impl Into<Value>) -> Result<(), SerializeError> {
+        self.out.push((self.prefix, value.into()));
+        Ok(())
+    }
+
+    fn compound(self, name: Option<&str>) -> Compound<'a> {
+        let prefix = match name {
+            Some(name) => join(&self.prefix, name),
+            None => self.prefix,
+        };
+        Compound {
+            prefix,
+            out: self.out,
+            index: 0,
+            key: None,
+        }
+    }
+}
+
+impl<'a> Serializer for Cells<'a> {
+    type Ok = ();
+    type Error = SerializeError;
+    type SerializeSeq = Sequence<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), SerializeError> {
+        self.push(i64::try_from(v).map_or(Value::Float(v as f64), Value::Integer))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), SerializeError> {
+        self.push(i64::try_from(v).map_or(Value::Float(v as f64), Value::Integer))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), SerializeError> {
+        self.push(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), SerializeError> {
+        self.push(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), SerializeError> {
+        self.push(String::from_utf8_lossy(v).into_owned())
+    }
+
+    fn serialize_none(self) -> Result<(), SerializeError> {
+        self.push(Value::Empty)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), SerializeError> {
+        self.push(Value::Empty)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), SerializeError> {
+        self.push(Value::Empty)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), SerializeError> {
+        self.push(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        let prefix = join(&self.prefix, variant);
+        value.serialize(Cells::new(prefix, self.out))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Sequence<'a>, SerializeError> {
+        Ok(Sequence {
+            prefix: self.prefix,
+            out: self.out,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound<'a>, SerializeError> {
+        Ok(self.compound(None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, SerializeError> {
+        Ok(self.compound(None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, SerializeError> {
+        Ok(self.compound(Some(variant)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>, SerializeError> {
+        Ok(self.compound(None))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, SerializeError> {
+        Ok(self.compound(None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, SerializeError> {
+        Ok(self.compound(Some(variant)))
+    }
+}
+
+// The fields of a struct, map or tuple, each recorded under its own name. Tuple
+// fields are named by position.
+struct Compound<'a> {
+    prefix: String,
+    out: &'a mut Vec<(String, Value)>,
+    index: usize,
+    key: Option<String>,
+}
+
+impl Compound<'_> {
+    fn field<T: ?Sized + Serialize>(
+        &mut self,
+        name: &str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        value.serialize(Cells::new(join(&self.prefix, name), self.out))
+    }
+
+    fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let name = self.index.to_string();
+        self.index += 1;
+        self.field(&name, value)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), SerializeError> {
+        self.key = Some(scalar_text(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| SerializeError("map value without a key".to_string()))?;
+        self.field(&key, value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        Ok(())
+    }
+}
+
+// A sequence of any length, kept in a single cell since its elements cannot map to
+// a fixed set of columns.
+struct Sequence<'a> {
+    prefix: String,
+    out: &'a mut Vec<(String, Value)>,
+    items: Vec<String>,
+}
+
+impl SerializeSeq for Sequence<'_> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.items.push(scalar_text(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), SerializeError> {
+        Cells::new(self.prefix, self.out).push(self.items.join(", "))
+    }
+}
+
+// `value` as a single piece of text. Compound values are written as `{a: 1, b: 2}`.
+fn scalar_text<T: ?Sized + Serialize>(value: &T) -> Result<String, SerializeError> {
+    let mut cells = Vec::new();
+    value.serialize(Cells::new(String::new(), &mut cells))?;
+    Ok(match cells.as_slice() {
+        [(key, value)] if key.is_empty() => value.to_string(),
+        _ => {
+            let fields: Vec<String> = cells
+                .iter()
+                .map(|(key, value)| format!("{}: {}", key, value))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        }
+    })
+}
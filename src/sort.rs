@@ -0,0 +1,271 @@
+use crate::value::{Date, Value};
+use std::{cmp::Ordering, time::Duration};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    pub(crate) fn indicator(&self) -> char {
+        match self {
+            Self::Ascending => '▲',
+            Self::Descending => '▼',
+        }
+    }
+}
+
+/// How two cells are compared when sorting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Comparator {
+    /// Numbers (including text that parses as a number) compare numerically, dates
+    /// and durations chronologically, everything else naturally. In a column of mixed
+    /// kinds, numbers come first, then dates, durations, booleans and text.
+    #[default]
+    Auto,
+    /// Digit runs compare by their numeric value, so `file2` sorts before `file10`.
+    /// Letters compare case-insensitively.
+    Natural,
+    /// Case-insensitive comparison of the displayed text.
+    CaseInsensitive,
+    /// Plain code point order of the displayed text.
+    Lexical,
+}
+
+impl Comparator {
+    pub(crate) fn compare(&self, a: &Value, b: &Value) -> Ordering {
+        match self {
+            Self::Auto => match (AutoKey::of(a), AutoKey::of(b)) {
+                (AutoKey::Number(a), AutoKey::Number(b)) => a.total_cmp(&b),
+                (AutoKey::Date(a), AutoKey::Date(b)) => a.cmp(&b),
+                (AutoKey::Duration(a), AutoKey::Duration(b)) => a.cmp(&b),
+                (AutoKey::Bool(a), AutoKey::Bool(b)) => a.cmp(&b),
+                (AutoKey::Text(a), AutoKey::Text(b)) => natural_cmp(&a, &b),
+                (a, b) => a.rank().cmp(&b.rank()),
+            },
+            Self::Natural => natural_cmp(&a.to_string(), &b.to_string()),
+            Self::CaseInsensitive => case_insensitive_cmp(&a.to_string(), &b.to_string()),
+            Self::Lexical => a.to_string().cmp(&b.to_string()),
+        }
+    }
+}
+
+// What `Comparator::Auto` compares a cell by. Cells of different kinds are ordered by
+// kind alone, which keeps the comparison a total order; comparing numerically only
+// when both sides parse as numbers would not be.
+enum AutoKey {
+    Number(f64),
+    Date(Date),
+    Duration(Duration),
+    Bool(bool),
+    Text(String),
+}
+
+impl AutoKey {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Date(date) => Self::Date(*date),
+            Value::Duration(duration) => Self::Duration(*duration),
+            Value::Bool(flag) => Self::Bool(*flag),
+            _ => match value.as_f64() {
+                Some(number) => Self::Number(number),
+                None => Self::Text(value.to_string()),
+            },
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Number(_) => 0,
+            Self::Date(_) => 1,
+            Self::Duration(_) => 2,
+            Self::Bool(_) => 3,
+            Self::Text(_) => 4,
+        }
+    }
+}
+
+/// Identifies a column either by position or by its header text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnKey {
+    Index(usize),
+    Header(String),
+}
+
+impl From<usize> for ColumnKey {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl From<&str> for ColumnKey {
+    fn from(header: &str) -> Self {
+        Self::Header(header.to_string())
+    }
+}
+
+impl From<String> for ColumnKey {
+    fn from(header: String) -> Self {
+        Self::Header(header)
+    }
+}
+
+/// One level of a multi-key sort, see [`Table::sort_by`](crate::Table::sort_by).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    pub(crate) column: ColumnKey,
+    pub(crate) order: SortOrder,
+    pub(crate) comparator: Comparator,
+}
+
+impl SortKey {
+    pub fn new(column: impl Into<ColumnKey>, order: SortOrder) -> Self {
+        Self {
+            column: column.into(),
+            order,
+            comparator: Comparator::default(),
+        }
+    }
+
+    pub fn with_comparator(mut self, comparator: Comparator) -> Self {
+        self.comparator = comparator;
+        self
+    }
+}
+
+fn case_insensitive_cmp(a: &str, b: &str) -> Ordering {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .cmp(b.chars().flat_map(char::to_lowercase))
+        .then_with(|| a.cmp(b))
+}
+
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a_rest, mut b_rest) = (a, b);
+    loop {
+        let (a_chunk, a_tail) = next_chunk(a_rest);
+        let (b_chunk, b_tail) = next_chunk(b_rest);
+        let ordering = match (a_chunk, b_chunk) {
+            (None, None) => return case_insensitive_cmp(a, b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_chunk), Some(b_chunk)) => {
+                let a_digits = a_chunk.starts_with(|ch: char| ch.is_ascii_digit());
+                let b_digits = b_chunk.starts_with(|ch: char| ch.is_ascii_digit());
+                match (a_digits, b_digits) {
+                    (true, true) => digits_cmp(a_chunk, b_chunk),
+                    _ => a_chunk
+                        .chars()
+                        .flat_map(char::to_lowercase)
+                        .cmp(b_chunk.chars().flat_map(char::to_lowercase)),
+                }
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        a_rest = a_tail;
+        b_rest = b_tail;
+    }
+}
+
+// Splits off the leading run of either ASCII digits or non-digits.
+fn next_chunk(text: &str) -> (Option<&str>, &str) {
+    let mut chars = text.chars();
+    let Some(first) = chars.next() else {
+        return (None, text);
+    };
+    let digits = first.is_ascii_digit();
+    let end = text
+        .find(|ch: char| ch.is_ascii_digit() != digits)
+        .unwrap_or(text.len());
+    let (chunk, rest) = text.split_at(end);
+    (Some(chunk), rest)
+}
+
+// Compares digit runs of any length by value without parsing them.
+fn digits_cmp(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> Vec<Value> {
+        vec![
+            Value::from("1.5"),
+            Value::from("1.10"),
+            Value::from("1.7x"),
+            Value::from("file2"),
+            Value::from("file10"),
+            Value::from("File2"),
+            Value::from(" 3 "),
+            Value::from("abc"),
+            Value::from(""),
+            Value::from(2),
+            Value::from(-1.25),
+            Value::from(f64::NAN),
+            Value::from(true),
+            Value::from(false),
+            Value::Date(Date::new(2024, 2, 29).unwrap()),
+            Value::Duration(Duration::from_millis(1500)),
+            Value::Empty,
+        ]
+    }
+
+    #[test]
+    fn comparators_are_total_orders() {
+        let values = values();
+        for comparator in [
+            Comparator::Auto,
+            Comparator::Natural,
+            Comparator::CaseInsensitive,
+            Comparator::Lexical,
+        ] {
+            for a in &values {
+                assert_eq!(comparator.compare(a, a), Ordering::Equal);
+                for b in &values {
+                    let ab = comparator.compare(a, b);
+                    assert_eq!(ab, comparator.compare(b, a).reverse(), "{a:?} {b:?}");
+                    for c in &values {
+                        let bc = comparator.compare(b, c);
+                        if ab == bc && ab != Ordering::Equal {
+                            assert_eq!(
+                                comparator.compare(a, c),
+                                ab,
+                                "{comparator:?} {a:?} {b:?} {c:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn auto_ranks_numbers_before_text() {
+        let mut values = [
+            Value::from("1.7x"),
+            Value::from("1.10"),
+            Value::from("1.5"),
+            Value::from("b"),
+            Value::from(10),
+            Value::from("A"),
+        ];
+        values.sort_by(|a, b| Comparator::Auto.compare(a, b));
+        let sorted: Vec<String> = values.iter().map(Value::to_string).collect();
+        assert_eq!(sorted, ["1.10", "1.5", "10", "1.7x", "A", "b"]);
+    }
+
+    #[test]
+    fn natural_order() {
+        let mut names = ["file10", "File2", "file2", "file1", "file01"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(names, ["file01", "file1", "File2", "file2", "file10"]);
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{
+    border::{BorderChars, BorderStyle},
+    column::{Column, Overflow},
+    render::{CellView, GridRenderer, GridRow},
+    rule::ColumnStats,
+    separator::SeparatorPolicy,
+    style::Style,
+    theme::Theme,
+    value::Value,
+    width::max_line_width,
+};
+use std::{fmt, io, mem, slice};
+
+#[derive(Default)]
+pub struct StreamingTableBuilder {
+    columns: Vec<Column>,
+    chars: Option<BorderChars>,
+    border_color: Style,
+    separators: SeparatorPolicy,
+    sample_rows: usize,
+}
+
+impl StreamingTableBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_column(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn style(mut self, style: BorderStyle) -> Self {
+        self.chars = Some(style.get_chars());
+        self
+    }
+
+    /// Sets both the border glyphs and the separators from a preset. Separators set
+    /// afterwards override those of the theme.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.chars = Some(theme.chars());
+        self.separators = theme.separators();
+        self
+    }
+
+    /// Sets the style the border glyphs are drawn with.
+    pub fn border_color(mut self, style: Style) -> Self {
+        self.border_color = style;
+        self
+    }
+
+    /// Sets which lines are drawn between and around the cells.
+    pub fn separators(mut self, separators: SeparatorPolicy) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Holds back the first `n` rows and sizes the columns to fit them, along with
+    /// the headers, before anything is written. Without a sample, columns are as
+    /// wide as their header or [`Column::with_width`]. Truncated columns are always
+    /// wide enough to show their ellipsis after at least one character.
+    pub fn sample_rows(mut self, n: usize) -> Self {
+        self.sample_rows = n;
+        self
+    }
+
+    /// Starts the table on `writer`, writing the header right away unless rows are
+    /// sampled first.
+    pub fn build<W: io::Write>(self, writer: W) -> io::Result<StreamingTable<W>> {
+        let mut table = StreamingTable {
+            writer,
+            columns: self.columns,
+            chars: self
+                .chars
+                .unwrap_or_else(|| BorderStyle::default().get_chars()),
+            border_color: self.border_color,
+            separators: self.separators,
+            sample_rows: self.sample_rows,
+            sample: Vec::new(),
+            widths: None,
+            last: None,
+            written: 0,
+        };
+        if table.sample_rows == 0 {
+            table.start()?;
+        }
+        Ok(table)
+    }
+}
+
+/// A table written to an [`io::Write`] row by row, for sources with no end in sight
+/// such as tailed logs.
+///
+/// Rows are not kept, so column widths are settled before the first row is written:
+/// either from the columns themselves or from a sample of the first rows, see
+/// [`StreamingTableBuilder::sample_rows`]. Content that does not fit is truncated or
+/// wrapped according to the column's [`Overflow`](crate::Overflow). Column rules
+/// that compare against the whole column, such as percentiles, are ignored.
+///
+/// ```text
+/// let mut table = StreamingTableBuilder::new()
+///     .add_column(Column::new("Time").with_width(8))
+///     .add_column(Column::new("Message").with_width(60))
+///     .build(io::stdout())?;
+/// for entry in entries {
+///     table.write_row(vec![entry.time, entry.message])?;
+/// }
+/// table.finish()?;
+/// ```
+///
+/// The bottom border is written by [`finish`](Self::finish); a table that is dropped
+/// instead is left open.
+pub struct StreamingTable<W: io::Write> {
+    writer: W,
+    columns: Vec<Column>,
+    chars: BorderChars,
+    border_color: Style,
+    separators: SeparatorPolicy,
+    sample_rows: usize,
+    /// Rows held back until the sample is complete.
+    sample: Vec<Vec<Value>>,
+    /// Total widths, padding included, once settled.
+    widths: Option<Vec<usize>>,
+    /// The last row written, header included, which the next line joins onto.
+    last: Option<GridRow>,
+    /// Number of data rows written.
+    written: usize,
+}
+
+impl<W: io::Write> StreamingTable<W> {
+    /// Writes a row, or holds it back while the sample is being collected.
+    pub fn write_row(&mut self, row: Vec<impl Into<Value>>) -> io::Result<()> {
+        let row: Vec<Value> = row.into_iter().map(Into::into).collect();
+        if self.widths.is_some() {
+            return self.emit(&row);
+        }
+
+        self.sample.push(row);
+        if self.sample.len() >= self.sample_rows {
+            self.flush_sample()?;
+        }
+        Ok(())
+    }
+
+    /// Writes any rows still held back and the bottom border, then returns the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.widths.is_none() {
+            self.flush_sample()?;
+        }
+        if let Some(last) = self.last.take() {
+            self.render(&[last], 1, true)?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn flush_sample(&mut self) -> io::Result<()> {
+        self.start()?;
+        for row in mem::take(&mut self.sample) {
+            self.emit(&row)?;
+        }
+        Ok(())
+    }
+
+    // Settles the widths and writes the header.
+    fn start(&mut self) -> io::Result<()> {
+        let widths = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                let content = self
+                    .sample
+                    .iter()
+                    .map(|row| {
+                        max_line_width(&col.format_value(row.get(i).unwrap_or(&Value::Empty)))
+                    })
+                    .fold(max_line_width(&col.header), usize::max);
+                // Later rows may be wider than anything seen so far, so leave room for
+                // the truncation marker and a character before it rather than cut them
+                // off without one.
+                let marker = match col.truncation.ellipsis_width() {
+                    0 => 0,
+                    _ if matches!(col.overflow, Overflow::Wrap) => 0,
+                    width => width + 1,
+                };
+                col.get_content_width(content.max(marker)) + col.padding
+            })
+            .collect();
+        self.widths = Some(widths);
+
+        let header = GridRow::single(
+            self.columns.iter().enumerate().map(|(i, col)| {
+                let view = CellView {
+                    text: col.header.clone(),
+                    alignment: col
+                        .header_alignment(self.sample.iter().filter_map(|row| row.get(i))),
+                    style: col.header_style,
+                };
+                (i, view)
+            }),
+            false,
+        );
+        self.render(slice::from_ref(&header), 0, false)?;
+        self.last = Some(header);
+        self.writer.flush()
+    }
+
+    fn emit(&mut self, row: &[Value]) -> io::Result<()> {
+        let stats = ColumnStats::default();
+        let cells = self.columns.iter().enumerate().map(|(i, col)| {
+            let value = row.get(i).unwrap_or(&Value::Empty);
+            let text = col.format_value(value);
+            let style = col
+                .rules
+                .iter()
+                .filter(|rule| !rule.needs_stats())
+                .filter_map(|rule| rule.style_for(value, &text, &stats))
+                .fold(col.style, Style::patch);
+            let view = CellView {
+                alignment: col.alignment_for(value),
+                style,
+                text,
+            };
+            (i, view)
+        });
+        let rule_above = match self.written {
+            0 => self.separators.header_line,
+            n => self.separators.row_lines.before(n),
+        };
+        let row = GridRow::single(cells, rule_above);
+
+        let last = self.last.take().expect("the header is written first");
+        let mut rows = vec![last, row];
+        self.render(&rows, 1, false)?;
+        self.last = rows.pop();
+        self.written += 1;
+        self.writer.flush()
+    }
+
+    fn render(&mut self, rows: &[GridRow], first: usize, close: bool) -> io::Result<()> {
+        let section = Section {
+            renderer: GridRenderer {
+                columns: &self.columns,
+                chars: self.chars,
+                border_color: self.border_color,
+                separators: self.separators,
+                header_rows: if self.written == 0 { 1 } else { 0 },
+            },
+            rows,
+            widths: self.widths.as_deref().unwrap_or_default(),
+            first,
+            close,
+        };
+        write!(self.writer, "{}", section)
+    }
+}
+
+// Rows drawn onto the end of what was already written.
+struct Section<'a> {
+    renderer: GridRenderer<'a>,
+    rows: &'a [GridRow],
+    widths: &'a [usize],
+    first: usize,
+    close: bool,
+}
+
+impl fmt::Display for Section<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.renderer
+            .render_rows(f, self.rows, self.widths, false, self.first, self.close)
+    }
+}
@@ -0,0 +1,236 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// An entry of the 256-color xterm palette.
+    Ansi256(u8),
+    /// A 24-bit truecolor value.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    // `base` is 30 for foreground and 40 for background colors.
+    fn sgr(&self, base: u8) -> String {
+        let named = |offset: u8| (base + offset).to_string();
+        let bright = |offset: u8| (base + 60 + offset).to_string();
+        match *self {
+            Self::Black => named(0),
+            Self::Red => named(1),
+            Self::Green => named(2),
+            Self::Yellow => named(3),
+            Self::Blue => named(4),
+            Self::Magenta => named(5),
+            Self::Cyan => named(6),
+            Self::White => named(7),
+            Self::BrightBlack => bright(0),
+            Self::BrightRed => bright(1),
+            Self::BrightGreen => bright(2),
+            Self::BrightYellow => bright(3),
+            Self::BrightBlue => bright(4),
+            Self::BrightMagenta => bright(5),
+            Self::BrightCyan => bright(6),
+            Self::BrightWhite => bright(7),
+            Self::Ansi256(index) => format!("{};5;{}", base + 8, index),
+            Self::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+        }
+    }
+
+    // The name used in HTML class hooks, such as `bright-red` or `rgb-ff8800`.
+    fn class_name(&self) -> String {
+        let name = match *self {
+            Self::Black => "black",
+            Self::Red => "red",
+            Self::Green => "green",
+            Self::Yellow => "yellow",
+            Self::Blue => "blue",
+            Self::Magenta => "magenta",
+            Self::Cyan => "cyan",
+            Self::White => "white",
+            Self::BrightBlack => "bright-black",
+            Self::BrightRed => "bright-red",
+            Self::BrightGreen => "bright-green",
+            Self::BrightYellow => "bright-yellow",
+            Self::BrightBlue => "bright-blue",
+            Self::BrightMagenta => "bright-magenta",
+            Self::BrightCyan => "bright-cyan",
+            Self::BrightWhite => "bright-white",
+            Self::Ansi256(index) => return format!("256-{}", index),
+            Self::Rgb(r, g, b) => return format!("rgb-{:02x}{:02x}{:02x}", r, g, b),
+        };
+        name.to_string()
+    }
+}
+
+/// Colors and text attributes rendered with ANSI escape sequences.
+///
+/// Styles are only emitted while [`color_enabled`] returns `true`, so the same table
+/// prints plain text when piped to a file or when `NO_COLOR` is set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    reverse: bool,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    pub fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Layers `other` on top of this style: its colors win where set and its
+    /// attributes are added.
+    pub fn patch(self, other: Style) -> Style {
+        Style {
+            fg: other.fg.or(self.fg),
+            bg: other.bg.or(self.bg),
+            bold: self.bold || other.bold,
+            dim: self.dim || other.dim,
+            italic: self.italic || other.italic,
+            underline: self.underline || other.underline,
+            reverse: self.reverse || other.reverse,
+        }
+    }
+
+    /// Wraps `text` in the escape sequences for this style.
+    pub fn paint(&self, text: &str) -> String {
+        if self.is_plain() || text.is_empty() || !color_enabled() {
+            return text.to_string();
+        }
+
+        let mut codes = Vec::new();
+        for (enabled, code) in [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+            (self.reverse, "7"),
+        ] {
+            if enabled {
+                codes.push(code.to_string());
+            }
+        }
+        if let Some(fg) = self.fg {
+            codes.push(fg.sgr(30));
+        }
+        if let Some(bg) = self.bg {
+            codes.push(bg.sgr(40));
+        }
+
+        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+    }
+
+    /// CSS classes standing for this style in HTML exports, e.g. `pinax-bold` and
+    /// `pinax-fg-red`. They do not depend on [`color_enabled`].
+    pub(crate) fn class_names(&self) -> Vec<String> {
+        let mut classes = Vec::new();
+        for (enabled, name) in [
+            (self.bold, "bold"),
+            (self.dim, "dim"),
+            (self.italic, "italic"),
+            (self.underline, "underline"),
+            (self.reverse, "reverse"),
+        ] {
+            if enabled {
+                classes.push(format!("pinax-{}", name));
+            }
+        }
+        if let Some(fg) = self.fg {
+            classes.push(format!("pinax-fg-{}", fg.class_name()));
+        }
+        if let Some(bg) = self.bg {
+            classes.push(format!("pinax-bg-{}", bg.class_name()));
+        }
+        classes
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color output only when stdout is a terminal and `NO_COLOR` is not set.
+    Auto,
+    Always,
+    Never,
+}
+
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+
+/// Overrides whether styles are emitted, for example from a `--color` flag.
+pub fn set_color_choice(choice: ColorChoice) {
+    COLOR_CHOICE.store(choice as u8, Ordering::Relaxed);
+}
+
+/// Returns whether styles are currently written as ANSI escape sequences.
+pub fn color_enabled() -> bool {
+    static DETECTED: OnceLock<bool> = OnceLock::new();
+
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        choice if choice == ColorChoice::Always as u8 => true,
+        choice if choice == ColorChoice::Never as u8 => false,
+        _ => *DETECTED.get_or_init(|| {
+            let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+            !no_color && io::stdout().is_terminal()
+        }),
+    }
+}
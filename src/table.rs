@@ -1,10 +1,34 @@
-use crate::{border::BorderStyle, column::Column};
-use std::fmt;
+use crate::{
+    alignment::Alignment,
+    border::{BorderChars, BorderStyle},
+    column::Column,
+    export::{self, CsvOptions},
+    header::{GroupSpan, HeaderGroup},
+    render::{widen, Anchor, CellView, GridRenderer, GridRow, Slot, SlotGrid},
+    rule::{ColumnStats, RowRule, Rule},
+    separator::SeparatorPolicy,
+    sort::{ColumnKey, SortKey, SortOrder},
+    style::Style,
+    terminal::terminal_width,
+    theme::Theme,
+    value::Value,
+    view::{GroupedView, Grouping, TableView},
+    width::max_line_width,
+};
+use std::{
+    cmp::Ordering,
+    fmt, io,
+    ops::{Deref, DerefMut, RangeBounds},
+};
 
 #[derive(Default)]
 pub struct TableBuilder {
     columns: Vec<Column>,
-    style: Option<BorderStyle>,
+    groups: Vec<GroupSpan>,
+    chars: Option<BorderChars>,
+    border_color: Style,
+    separators: SeparatorPolicy,
+    capacity: usize,
 }
 
 impl TableBuilder {
@@ -17,21 +41,140 @@ impl TableBuilder {
         self
     }
 
+    /// Adds the columns of `group`, with the group title spanning their headers.
+    pub fn add_group(mut self, group: HeaderGroup) -> Self {
+        group.flatten(0, &mut self.columns, &mut self.groups);
+        self
+    }
+
     pub fn style(mut self, style: BorderStyle) -> Self {
-        self.style = Some(style);
+        self.chars = Some(style.get_chars());
+        self
+    }
+
+    /// Sets both the border glyphs and the separators from a preset. Separators set
+    /// afterwards override those of the theme.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.chars = Some(theme.chars());
+        self.separators = theme.separators();
+        self
+    }
+
+    /// Sets the style the border glyphs are drawn with.
+    pub fn border_color(mut self, style: Style) -> Self {
+        self.border_color = style;
+        self
+    }
+
+    /// Sets which lines are drawn between and around the cells.
+    pub fn separators(mut self, separators: SeparatorPolicy) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Reserves room for `rows` rows up front.
+    pub fn capacity(mut self, rows: usize) -> Self {
+        self.capacity = rows;
         self
     }
 
     pub fn build(self) -> Table {
-        Table::new(self.columns, self.style.unwrap_or_default())
+        let mut table = Table::new(
+            self.columns,
+            self.chars
+                .unwrap_or_else(|| BorderStyle::default().get_chars()),
+            self.border_color,
+        );
+        table.header_groups = self.groups;
+        table.separators = self.separators;
+        table.rows.reserve(self.capacity);
+        table
     }
 }
 
 pub struct Table {
-    columns: Vec<Column>,
-    rows: Vec<Vec<String>>,
-    style: BorderStyle,
-    column_widths: Vec<usize>,
+    pub(crate) columns: Vec<Column>,
+    header_groups: Vec<GroupSpan>,
+    pub(crate) rows: Vec<Row>,
+    chars: BorderChars,
+    border_color: Style,
+    separators: SeparatorPolicy,
+    /// Width of the widest cell of each column, kept up to date as rows are added
+    /// and measured again only when cells change or go away.
+    cell_widths: Vec<usize>,
+    row_rules: Vec<RowRule>,
+    rules_enabled: bool,
+    sorted_by: Vec<(usize, SortOrder)>,
+    sort_indicator: bool,
+}
+
+pub(crate) struct Row {
+    pub(crate) cells: Vec<Value>,
+    style: Style,
+    cell_styles: Vec<Style>,
+    spans: Vec<Span>,
+}
+
+impl Row {
+    fn new(cells: Vec<Value>) -> Self {
+        Self {
+            cells,
+            style: Style::default(),
+            cell_styles: Vec::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    pub(crate) fn span_at(&self, col: usize) -> Option<&Span> {
+        self.spans.iter().find(|span| span.col == col)
+    }
+}
+
+/// Mutable access to the cells of a row, returned by [`Table::row_mut`].
+///
+/// Dereferences to the row's cells. The table's column widths are updated when it
+/// is dropped.
+pub struct RowMut<'a> {
+    table: &'a mut Table,
+    index: usize,
+    /// Widths of the cells before any change.
+    widths: Vec<Option<usize>>,
+}
+
+impl Deref for RowMut<'_> {
+    type Target = Vec<Value>;
+
+    fn deref(&self) -> &Vec<Value> {
+        &self.table.rows[self.index].cells
+    }
+}
+
+impl DerefMut for RowMut<'_> {
+    fn deref_mut(&mut self) -> &mut Vec<Value> {
+        &mut self.table.rows[self.index].cells
+    }
+}
+
+impl Drop for RowMut<'_> {
+    fn drop(&mut self) {
+        let new = self.table.row_widths(self.index);
+        self.table.update_widths(&self.widths, &new);
+        self.table.sorted_by.clear();
+    }
+}
+
+/// A merged cell anchored in a row, covering `rows` rows and `cols` columns.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Span {
+    pub(crate) col: usize,
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+}
+
+impl Span {
+    fn covers(&self, col: usize) -> bool {
+        self.col <= col && col < self.col.saturating_add(self.cols)
+    }
 }
 
 impl Table {
@@ -39,118 +182,1231 @@ impl Table {
         TableBuilder::new()
     }
 
-    fn new(columns: Vec<Column>, style: BorderStyle) -> Self {
-        let column_widths = columns.iter().map(|col| col.total_width()).collect();
+    /// Creates a table with the given columns and room for `rows` rows, like
+    /// [`Vec::with_capacity`]. Use [`TableBuilder::capacity`] to combine it with other
+    /// builder options.
+    pub fn with_capacity(columns: impl IntoIterator<Item = Column>, rows: usize) -> Table {
+        columns
+            .into_iter()
+            .fold(TableBuilder::new(), TableBuilder::add_column)
+            .capacity(rows)
+            .build()
+    }
+
+    fn new(columns: Vec<Column>, chars: BorderChars, border_color: Style) -> Self {
+        let cell_widths = vec![0; columns.len()];
 
         Self {
             columns,
+            header_groups: Vec::new(),
             rows: Vec::new(),
-            style,
-            column_widths,
+            chars,
+            border_color,
+            separators: SeparatorPolicy::default(),
+            cell_widths,
+            row_rules: Vec::new(),
+            rules_enabled: true,
+            sorted_by: Vec::new(),
+            sort_indicator: false,
         }
     }
 
     fn calculate_column_widths(&mut self) {
-        self.column_widths = self
-            .columns
+        self.cell_widths = (0..self.columns.len())
+            .map(|i| self.widest_cell(i))
+            .collect();
+    }
+
+    fn widest_cell(&self, index: usize) -> usize {
+        self.rows
             .iter()
-            .enumerate()
-            .map(|(i, col)| {
-                let content_widths = std::iter::once(&col.header)
-                    .chain(self.rows.iter().filter_map(|row| row.get(i)))
-                    .map(|s| s.len());
+            .filter_map(|row| self.cell_width(row, index))
+            .fold(0, usize::max)
+    }
+
+    fn row_widths(&self, row: usize) -> Vec<Option<usize>> {
+        (0..self.columns.len())
+            .map(|i| self.cell_width(&self.rows[row], i))
+            .collect()
+    }
+
+    // Updates the running widths after cells `old` wide were replaced by cells `new`
+    // wide. Columns are only measured again when their widest cell shrank.
+    fn update_widths(&mut self, old: &[Option<usize>], new: &[Option<usize>]) {
+        for (i, (&old, &new)) in old.iter().zip(new).enumerate() {
+            let widest = self.cell_widths[i];
+            let new = new.unwrap_or(0);
+            if new >= widest {
+                self.cell_widths[i] = new;
+            } else if old == Some(widest) {
+                self.cell_widths[i] = self.widest_cell(i);
+            }
+        }
+    }
+
+    // Widens the running cell widths for the rows from `first` on.
+    fn track_widths(&mut self, first: usize) {
+        for i in 0..self.columns.len() {
+            let widest = self.rows[first..]
+                .iter()
+                .filter_map(|row| self.cell_width(row, i))
+                .fold(0, usize::max);
+            self.cell_widths[i] = self.cell_widths[i].max(widest);
+        }
+    }
+
+    // Width of the cell of `row` in column `index`, or `None` for cells merged
+    // across columns, which are fitted when the table is drawn.
+    fn cell_width(&self, row: &Row, index: usize) -> Option<usize> {
+        let spans_columns = row
+            .span_at(index)
+            .is_some_and(|span| span.cols > 1 && index + 1 < self.columns.len());
+        let value = row.cells.get(index).unwrap_or(&Value::Empty);
+        (!spans_columns).then(|| max_line_width(&self.columns[index].format_value(value)))
+    }
 
-                let max_content = content_widths.max().unwrap_or(0);
-                col.get_content_width(max_content) + col.padding
+    // Total width, padding included, that column `index` needs to show `rows` and
+    // their footer.
+    fn measure_column<'a>(&self, index: usize, rows: impl IntoIterator<Item = &'a Row>) -> usize {
+        let rows: Vec<&Row> = rows.into_iter().collect();
+        let content = rows
+            .iter()
+            .filter_map(|row| self.cell_width(row, index))
+            .fold(0, usize::max);
+        self.column_width(index, content, &rows)
+    }
+
+    // Total width for cells up to `content` wide, with room for the header and the
+    // footer of `rows`.
+    fn column_width(&self, index: usize, content: usize, rows: &[&Row]) -> usize {
+        let col = &self.columns[index];
+        let footer = if col.has_footer() {
+            let values: Vec<&Value> = rows
+                .iter()
+                .map(|row| row.cells.get(index).unwrap_or(&Value::Empty))
+                .collect();
+            max_line_width(&col.format_footer(&col.footer_value(&values)))
+        } else {
+            0
+        };
+        let content = content
+            .max(max_line_width(&self.header_text(index)))
+            .max(footer);
+        col.get_content_width(content) + col.padding
+    }
+
+    // Whether any of the visible `columns` shows something in the footer row.
+    pub(crate) fn has_footer(&self, columns: &[usize]) -> bool {
+        columns.iter().any(|&i| self.columns[i].has_footer())
+    }
+
+    pub(crate) fn footer_cells(&self, columns: &[usize], rows: &[usize]) -> Vec<CellView> {
+        self.summary_cells(columns, rows, |col, values, _| col.footer_value(values))
+    }
+
+    // Aggregates of a group of rows. Columns without an aggregate stay blank, except
+    // the first, which is labelled instead.
+    fn subtotal_cells(&self, columns: &[usize], rows: &[usize]) -> Vec<CellView> {
+        self.summary_cells(columns, rows, |col, values, position| {
+            match &col.aggregate {
+                Some(aggregate) => aggregate.compute(values),
+                None if position == 0 => Value::from("Subtotal"),
+                None => Value::Empty,
+            }
+        })
+    }
+
+    fn summary_cells(
+        &self,
+        columns: &[usize],
+        rows: &[usize],
+        summarize: impl Fn(&Column, &[&Value], usize) -> Value,
+    ) -> Vec<CellView> {
+        columns
+            .iter()
+            .enumerate()
+            .map(|(position, &i)| {
+                let col = &self.columns[i];
+                let values: Vec<&Value> = rows
+                    .iter()
+                    .map(|&row| self.rows[row].cells.get(i).unwrap_or(&Value::Empty))
+                    .collect();
+                let value = summarize(col, &values, position);
+                CellView {
+                    text: col.format_footer(&value),
+                    alignment: col.alignment_for(&value),
+                    style: col.style,
+                }
             })
-            .collect();
+            .collect()
+    }
+
+    pub fn add_row(&mut self, row: Vec<impl Into<Value>>) {
+        self.add_rows([row]);
+    }
+
+    /// Adds every row of `rows`, like [`add_row`](Self::add_row) in a loop.
+    pub fn add_rows<V: Into<Value>>(&mut self, rows: impl IntoIterator<Item = Vec<V>>) {
+        let first = self.rows.len();
+        let rows = rows.into_iter();
+        self.rows.reserve(rows.size_hint().0);
+        self.rows
+            .extend(rows.map(|row| Row::new(row.into_iter().map(Into::into).collect())));
+        self.sorted_by.clear();
+        self.track_widths(first);
     }
 
-    pub fn add_row(&mut self, row: Vec<impl Into<String>>) {
-        let row: Vec<String> = row.into_iter().map(Into::into).collect();
+    /// Adds a row with a single cell spanning every column, e.g. a section title.
+    pub fn add_section_row(&mut self, title: impl Into<Value>) {
+        let mut row = Row::new(vec![title.into()]);
+        row.spans.push(Span {
+            col: 0,
+            rows: 1,
+            cols: usize::MAX,
+        });
         self.rows.push(row);
+        self.sorted_by.clear();
+        self.track_widths(self.rows.len() - 1);
+    }
+
+    /// Merges the data cells from (`row`, `col`) across `rowspan` rows and `colspan`
+    /// columns. The merged cell shows the value and style of its top-left cell; the
+    /// values of the other cells are cleared.
+    ///
+    /// Spans are clipped to the table. A merge only shows where its rows are
+    /// displayed next to each other in their original order and its columns are
+    /// displayed in order, so sorting or filtering the rows cuts it short.
+    pub fn merge_cells(&mut self, row: usize, col: usize, rowspan: usize, colspan: usize) {
+        if row >= self.rows.len() || col >= self.columns.len() || rowspan == 0 || colspan == 0 {
+            return;
+        }
+        let rowspan = rowspan.min(self.rows.len() - row);
+        let colspan = colspan.min(self.columns.len() - col);
+
+        for (offset, covered) in self.rows[row..row + rowspan].iter_mut().enumerate() {
+            for (i, cell) in covered.cells.iter_mut().enumerate().skip(col).take(colspan) {
+                if offset > 0 || i > col {
+                    *cell = Value::Empty;
+                }
+            }
+        }
+
+        let anchor = &mut self.rows[row];
+        anchor.spans.retain(|span| span.col != col);
+        anchor.spans.push(Span {
+            col,
+            rows: rowspan,
+            cols: colspan,
+        });
         self.calculate_column_widths();
     }
 
-    fn write_row(
+    /// Inserts a row before the row at `index`, or after the last row if `index` is
+    /// past the end. Merged cells the new row would fall inside end above it.
+    pub fn insert_row(&mut self, index: usize, row: Vec<impl Into<Value>>) {
+        let index = index.min(self.rows.len());
+        for (r, above) in self.rows[..index].iter_mut().enumerate() {
+            for span in &mut above.spans {
+                span.rows = span.rows.min(index - r);
+            }
+        }
+        let row = Row::new(row.into_iter().map(Into::into).collect());
+        self.rows.insert(index, row);
+        self.sorted_by.clear();
+
+        let old = vec![None; self.columns.len()];
+        let new = self.row_widths(index);
+        self.update_widths(&old, &new);
+    }
+
+    /// Removes the row at `index` and returns its cells. Merged cells anchored in it
+    /// go with it, while those reaching into it from above lose a row.
+    pub fn remove_row(&mut self, index: usize) -> Option<Vec<Value>> {
+        if index >= self.rows.len() {
+            return None;
+        }
+        for (r, above) in self.rows[..index].iter_mut().enumerate() {
+            for span in &mut above.spans {
+                if r + span.rows > index {
+                    span.rows -= 1;
+                }
+            }
+        }
+        let old = self.row_widths(index);
+        let row = self.rows.remove(index);
+
+        let new = vec![None; self.columns.len()];
+        self.update_widths(&old, &new);
+        Some(row.cells)
+    }
+
+    /// Keeps only the rows for which `keep` returns `true`, in their order. Merged
+    /// cells shrink to the rows that stay.
+    pub fn retain(&mut self, mut keep: impl FnMut(&[Value]) -> bool) {
+        let kept: Vec<bool> = self.rows.iter().map(|row| keep(&row.cells)).collect();
+        for (r, row) in self.rows.iter_mut().enumerate() {
+            for span in &mut row.spans {
+                let end = r.saturating_add(span.rows).min(kept.len());
+                span.rows = kept[r..end].iter().filter(|&&k| k).count();
+            }
+        }
+
+        let mut kept = kept.into_iter();
+        self.rows.retain(|_| kept.next().expect("one flag per row"));
+        self.calculate_column_widths();
+    }
+
+    /// Removes every row, keeping the columns and their settings.
+    pub fn clear(&mut self) {
+        self.rows.clear();
+        self.sorted_by.clear();
+        self.cell_widths.fill(0);
+    }
+
+    /// Replaces the value of a single data cell.
+    pub fn set_cell(&mut self, row: usize, col: usize, value: impl Into<Value>) {
+        if row >= self.rows.len() || col >= self.columns.len() {
+            return;
+        }
+        let old = self.row_widths(row);
+        let cells = &mut self.rows[row].cells;
+        if cells.len() <= col {
+            cells.resize(col + 1, Value::Empty);
+        }
+        cells[col] = value.into();
+        self.sorted_by.clear();
+
+        let new = self.row_widths(row);
+        self.update_widths(&old, &new);
+    }
+
+    /// Gives mutable access to the cells of the row at `index`, e.g. to update a
+    /// monitor in place between redraws. Column widths follow once the returned
+    /// [`RowMut`] is dropped.
+    pub fn row_mut(&mut self, index: usize) -> Option<RowMut<'_>> {
+        if index >= self.rows.len() {
+            return None;
+        }
+        let widths = self.row_widths(index);
+        Some(RowMut {
+            table: self,
+            index,
+            widths,
+        })
+    }
+
+    /// Adds a column after the existing ones, shown empty in rows that have no cell
+    /// for it yet.
+    pub fn add_column(&mut self, column: Column) {
+        self.columns.push(column);
+        self.calculate_column_widths();
+    }
+
+    /// Removes the column at `index` along with its cells, and returns it.
+    ///
+    /// Header groups lose the column and disappear once empty. Merged cells across
+    /// it become one column narrower, keeping their content. Row rules see the
+    /// remaining cells, so those that read cells by position may need updating.
+    pub fn remove_column(&mut self, index: usize) -> Option<Column> {
+        if index >= self.columns.len() {
+            return None;
+        }
+
+        let n = self.columns.len();
+        for row in &mut self.rows {
+            for span in &mut row.spans {
+                // The next column takes over the anchor and its value.
+                if span.col == index && span.cols > 1 && index + 1 < n {
+                    row.cells
+                        .resize(row.cells.len().max(index + 2), Value::Empty);
+                    row.cells[index + 1] = row.cells[index].clone();
+                }
+                if span.col > index {
+                    span.col -= 1;
+                } else if span.covers(index) {
+                    span.cols -= 1;
+                }
+            }
+            row.spans.retain(|span| span.cols > 0);
+            if index < row.cells.len() {
+                row.cells.remove(index);
+            }
+            if index < row.cell_styles.len() {
+                row.cell_styles.remove(index);
+            }
+        }
+
+        for group in &mut self.header_groups {
+            group.remove_column(index);
+        }
+        self.header_groups.retain(|group| group.start < group.end);
+        self.sorted_by.retain(|&(col, _)| col != index);
+        for (col, _) in &mut self.sorted_by {
+            if *col > index {
+                *col -= 1;
+            }
+        }
+
+        let column = self.columns.remove(index);
+        self.calculate_column_widths();
+        Some(column)
+    }
+
+    /// Moves the column at `from`, with its cells, so that it ends up at `to`.
+    ///
+    /// The column stays in its header groups if it lands next to or inside them,
+    /// and joins other groups only when it lands strictly inside. Merged cells that
+    /// the move would split apart are unmerged, leaving every cell in its own column.
+    pub fn move_column(&mut self, from: usize, to: usize) {
+        let n = self.columns.len();
+        if from >= n || to >= n || from == to {
+            return;
+        }
+        // Where the column at `col` ends up.
+        let position = |col: usize| match col {
+            col if col == from => to,
+            col if from < col && col <= to => col - 1,
+            col if to <= col && col < from => col + 1,
+            col => col,
+        };
+
+        for row in &mut self.rows {
+            row.cells.resize(row.cells.len().max(n), Value::Empty);
+            let cell = row.cells.remove(from);
+            row.cells.insert(to, cell);
+            if !row.cell_styles.is_empty() {
+                row.cell_styles.resize(n, Style::default());
+                let style = row.cell_styles.remove(from);
+                row.cell_styles.insert(to, style);
+            }
+
+            let cells = &mut row.cells;
+            row.spans.retain_mut(|span| {
+                let end = span.col.saturating_add(span.cols).min(n);
+                let moved: Vec<usize> = (span.col..end).map(position).collect();
+                let first = moved.iter().copied().min().unwrap_or(span.col);
+                let last = moved.iter().copied().max().unwrap_or(span.col);
+                if last - first + 1 != moved.len() {
+                    // Split apart: every cell stays in its own column.
+                    return false;
+                }
+                // The value of the merged cell stays in its top-left cell.
+                cells.swap(first, position(span.col));
+                span.col = first;
+                true
+            });
+        }
+
+        let members: Vec<bool> = self
+            .header_groups
+            .iter()
+            .map(|group| group.contains(from))
+            .collect();
+        for (group, member) in self.header_groups.iter_mut().zip(members) {
+            group.remove_column(from);
+            group.insert_column(to, member);
+        }
+        self.header_groups.retain(|group| group.start < group.end);
+        for (col, _) in &mut self.sorted_by {
+            *col = position(*col);
+        }
+
+        let column = self.columns.remove(from);
+        self.columns.insert(to, column);
+        self.calculate_column_widths();
+    }
+
+    pub(crate) fn column_index(&self, key: &ColumnKey) -> Option<usize> {
+        match key {
+            ColumnKey::Index(index) => (*index < self.columns.len()).then_some(*index),
+            ColumnKey::Header(header) => self.columns.iter().position(|col| &col.header == header),
+        }
+    }
+
+    /// Sorts the data rows by one column, comparing cells with
+    /// [`Comparator::Auto`](crate::Comparator::Auto).
+    pub fn sort_by_column(&mut self, column: impl Into<ColumnKey>, order: SortOrder) {
+        self.sort_by(&[SortKey::new(column, order)]);
+    }
+
+    /// Sorts the data rows by several columns; later keys break ties in earlier ones.
+    ///
+    /// The sort is stable and empty cells always sort last. Keys naming a column that
+    /// does not exist are ignored. Merged cells keep the rows that still follow their
+    /// top-left cell.
+    pub fn sort_by(&mut self, keys: &[SortKey]) {
+        let keys: Vec<(usize, &SortKey)> = keys
+            .iter()
+            .filter_map(|key| self.column_index(&key.column).map(|i| (i, key)))
+            .collect();
+        if keys.is_empty() {
+            return;
+        }
+
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&self.rows[a], &self.rows[b]);
+            keys.iter()
+                .map(|&(i, key)| {
+                    let a = a.cells.get(i).unwrap_or(&Value::Empty);
+                    let b = b.cells.get(i).unwrap_or(&Value::Empty);
+                    match (a.is_empty(), b.is_empty()) {
+                        (true, true) => Ordering::Equal,
+                        (true, false) => Ordering::Greater,
+                        (false, true) => Ordering::Less,
+                        (false, false) => match key.order {
+                            SortOrder::Ascending => key.comparator.compare(a, b),
+                            SortOrder::Descending => key.comparator.compare(b, a),
+                        },
+                    }
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+
+        // Merged cells keep only the rows that still follow their anchor.
+        let mut position = vec![0; order.len()];
+        for (new, &old) in order.iter().enumerate() {
+            position[old] = new;
+        }
+        for (r, row) in self.rows.iter_mut().enumerate() {
+            for span in &mut row.spans {
+                span.rows = (0..span.rows)
+                    .take_while(|&k| position.get(r + k) == Some(&(position[r] + k)))
+                    .count();
+            }
+        }
+        let mut rows: Vec<Option<Row>> = self.rows.drain(..).map(Some).collect();
+        self.rows = order
+            .iter()
+            .map(|&old| rows[old].take().expect("each row is placed once"))
+            .collect();
+
+        self.sorted_by = keys.iter().map(|&(i, key)| (i, key.order)).collect();
+    }
+
+    /// Shows a ▲ or ▼ after the headers of the columns the table is sorted by.
+    pub fn set_sort_indicator(&mut self, enabled: bool) {
+        self.sort_indicator = enabled;
+    }
+
+    /// Titles of the header groups above column `index`, outermost first.
+    pub(crate) fn group_titles(&self, index: usize) -> Vec<&str> {
+        self.header_groups
+            .iter()
+            .filter(|group| group.contains(index))
+            .map(|group| group.title.as_str())
+            .collect()
+    }
+
+    fn header_text(&self, index: usize) -> String {
+        let header = &self.columns[index].header;
+        let order = self
+            .sorted_by
+            .iter()
+            .find(|(col, _)| *col == index)
+            .map(|(_, order)| order);
+        match order {
+            Some(order) if self.sort_indicator => format!("{} {}", header, order.indicator()),
+            _ => header.clone(),
+        }
+    }
+
+    /// Replaces the border glyphs, for tables that were not made with the builder
+    /// such as those from [`Table::from_rows`].
+    pub fn set_border_style(&mut self, style: BorderStyle) {
+        self.chars = style.get_chars();
+    }
+
+    /// Sets the style of every cell in a data row, layered over the column styles.
+    pub fn set_row_style(&mut self, row: usize, style: Style) {
+        if let Some(row) = self.rows.get_mut(row) {
+            row.style = style;
+        }
+    }
+
+    /// Sets the style of a single data cell, layered over its row and column styles.
+    pub fn set_cell_style(&mut self, row: usize, col: usize, style: Style) {
+        if let Some(row) = self.rows.get_mut(row) {
+            if row.cell_styles.len() <= col {
+                row.cell_styles.resize(col + 1, Style::default());
+            }
+            row.cell_styles[col] = style;
+        }
+    }
+
+    /// Adds a highlight for whole data rows. Row rules are applied over the row
+    /// style and under individual cell styles.
+    pub fn add_row_rule(&mut self, rule: RowRule) {
+        self.row_rules.push(rule);
+    }
+
+    /// Turns evaluation of column and row rules on or off. Rules are evaluated at
+    /// render time, so this only affects how the table is drawn.
+    pub fn set_rules_enabled(&mut self, enabled: bool) {
+        self.rules_enabled = enabled;
+    }
+
+    // Header rows for the visible `columns`: one row per level of visible header
+    // groups, then the column headers, each stretched up to the group above it.
+    fn header_rows(&self, columns: &[usize]) -> Vec<GridRow> {
+        let visible = |group: &&GroupSpan| columns.iter().any(|&i| group.contains(i));
+        let levels = self
+            .header_groups
+            .iter()
+            .filter(visible)
+            .map(|group| group.depth + 1)
+            .max()
+            .unwrap_or(0);
+        let n = columns.len();
+        let mut grid = SlotGrid::new(levels + 1, n, 0);
+
+        for depth in 0..=levels {
+            for j in 0..n {
+                if !grid.is_free(depth, j) {
+                    continue;
+                }
+                let column = columns[j];
+                let group = self
+                    .header_groups
+                    .iter()
+                    .find(|group| group.depth == depth && group.contains(column));
+                let anchor = match group {
+                    Some(group) => Anchor {
+                        view: CellView {
+                            text: group.title.clone(),
+                            alignment: group.alignment,
+                            style: group.style,
+                        },
+                        column,
+                        rowspan: 1,
+                        colspan: 1
+                            + (j + 1..n)
+                                .take_while(|&k| group.contains(columns[k]))
+                                .count(),
+                    },
+                    None => Anchor {
+                        view: CellView {
+                            text: self.header_text(column),
+                            alignment: self.columns[column].header_alignment(
+                                self.rows.iter().filter_map(|row| row.cells.get(column)),
+                            ),
+                            style: self.columns[column].header_style,
+                        },
+                        column,
+                        rowspan: levels + 1 - depth,
+                        colspan: 1,
+                    },
+                };
+                grid.place(depth, j, anchor);
+            }
+        }
+
+        grid.into_rows(|depth| depth > 0)
+    }
+
+    fn row_cells(&self, row: &Row, stats: &[ColumnStats]) -> Vec<CellView> {
+        let row_rule_style = self
+            .row_rules
+            .iter()
+            .filter(|_| self.rules_enabled)
+            .filter_map(|rule| rule.style_for(&row.cells))
+            .fold(row.style, Style::patch);
+
+        self.columns
+            .iter()
+            .zip(stats)
+            .enumerate()
+            .map(|(i, (col, stats))| {
+                let value = row.cells.get(i).unwrap_or(&Value::Empty);
+                let text = col.format_value(value);
+                let rule_style = col
+                    .rules
+                    .iter()
+                    .filter(|_| self.rules_enabled)
+                    .filter_map(|rule| rule.style_for(value, &text, stats))
+                    .fold(row_rule_style, Style::patch);
+                let cell_style = row.cell_styles.get(i).copied().unwrap_or_default();
+                CellView {
+                    alignment: col.alignment_for(value),
+                    style: col.style.patch(rule_style).patch(cell_style),
+                    text,
+                }
+            })
+            .collect()
+    }
+
+    fn column_stats(&self, rows: &[usize]) -> Vec<ColumnStats> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                if self.rules_enabled && col.rules.iter().any(Rule::needs_stats) {
+                    ColumnStats::new(rows.iter().filter_map(|&row| self.rows[row].cells.get(i)))
+                } else {
+                    ColumnStats::default()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a view of every row and column, to be narrowed with the methods of
+    /// [`TableView`].
+    pub fn view(&self) -> TableView<'_> {
+        TableView::new(self)
+    }
+
+    pub fn filter(&self, predicate: impl Fn(&[Value]) -> bool) -> TableView<'_> {
+        self.view().filter(predicate)
+    }
+
+    pub fn where_eq(&self, column: impl Into<ColumnKey>, text: &str) -> TableView<'_> {
+        self.view().where_eq(column, text)
+    }
+
+    pub fn select_columns<K: Into<ColumnKey>>(
         &self,
-        f: &mut fmt::Formatter<'_>,
-        row: &[String],
-        chars: &crate::border::BorderChars,
-    ) -> fmt::Result {
-        write!(f, "{}", chars.vertical)?;
+        columns: impl IntoIterator<Item = K>,
+    ) -> TableView<'_> {
+        self.view().select_columns(columns)
+    }
+
+    pub fn head(&self, n: usize) -> TableView<'_> {
+        self.view().head(n)
+    }
+
+    pub fn tail(&self, n: usize) -> TableView<'_> {
+        self.view().tail(n)
+    }
+
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> TableView<'_> {
+        self.view().slice(range)
+    }
+
+    /// Groups the rows by their cell in `column`, under a title row per group.
+    pub fn group_by(&self, column: impl Into<ColumnKey>) -> GroupedView<'_> {
+        self.view().group_by(column)
+    }
+
+    /// Writes the table as a GitHub-flavored Markdown table, for pasting into issues
+    /// and pull requests.
+    ///
+    /// Cells are formatted like in the terminal, with pipes escaped and line breaks
+    /// written as `<br>`. Column alignments become `:---`, `:---:` and `---:`, and
+    /// numeric columns without one are right-aligned like in the terminal.
+    /// Markdown has no merged cells or footers: merged cells keep their text in the
+    /// top-left cell, while section rows and the footer are written in bold.
+    pub fn to_markdown(&self) -> String {
+        self.view().to_markdown()
+    }
+
+    /// Writes the table as an HTML `<table>`, for embedding in generated reports.
+    ///
+    /// Header groups and column headers go in `<thead>`, the data rows in `<tbody>`
+    /// and the footer in `<tfoot>`. Every cell carries its alignment as an inline
+    /// `text-align`, merged cells become `colspan` and `rowspan`, and cell styles
+    /// become classes such as `pinax-bold` or `pinax-fg-red` for a stylesheet to
+    /// target. Text is escaped and line breaks are written as `<br>`.
+    pub fn to_html(&self) -> String {
+        self.view().to_html()
+    }
+
+    /// Writes the data rows as a JSON array with one object per row, keyed by column
+    /// header, for tools that offer `--output json`.
+    ///
+    /// Cells keep their type rather than their formatting: integers, floats and
+    /// durations (in seconds) become numbers, booleans stay booleans, dates become
+    /// `YYYY-MM-DD` strings and empty cells `null`. Non-finite floats are written as
+    /// `null`. Styles, merged cells and the footer are left out.
+    pub fn to_json(&self) -> String {
+        self.view().to_json()
+    }
+
+    /// Writes the data rows as newline-delimited JSON, one object per line as in
+    /// [`to_json`](Self::to_json), without holding the whole output in memory.
+    pub fn to_ndjson(&self, writer: impl io::Write) -> io::Result<()> {
+        self.view().to_ndjson(writer)
+    }
+
+    /// Writes the table as CSV, or another delimiter-separated format set in
+    /// `options`.
+    ///
+    /// Fields are quoted as RFC 4180 describes when they contain the delimiter, a
+    /// quote or a line break, and lines end with `\n`. Headers are prefixed with the
+    /// titles of their header groups. Cells hold their plain values unless
+    /// [`CsvOptions::with_formatted_values`] is set; styles, merged cells and the
+    /// footer are left out.
+    pub fn to_csv(&self, writer: impl io::Write, options: &CsvOptions) -> io::Result<()> {
+        self.view().to_csv(writer, options)
+    }
+
+    /// Reads a table from CSV, or another delimiter-separated format set in
+    /// `options`, with a column for each field of the header line.
+    ///
+    /// Quoted fields may contain delimiters, doubled quotes and line breaks. Rows
+    /// with more fields than the header get extra columns named by position. Fails
+    /// with [`io::ErrorKind::InvalidData`] on a quote that is never closed. Printing
+    /// a CSV file takes two lines:
+    ///
+    /// ```text
+    /// let table = Table::from_csv(std::io::stdin(), &CsvOptions::new())?;
+    /// print!("{}", table);
+    /// ```
+    pub fn from_csv(reader: impl io::Read, options: &CsvOptions) -> io::Result<Table> {
+        export::read_csv(reader, options)
+    }
+
+    /// Renders the table so that it is at most `max_width` columns wide.
+    ///
+    /// Columns are shrunk towards their minimum width, lowest priority first, which
+    /// makes their content wrap or truncate according to their overflow mode. If the
+    /// table still does not fit, low priority columns are hidden and a
+    /// "+N hidden columns" note is written below the table.
+    pub fn render_with_width(&self, max_width: usize) -> String {
+        let rows: Vec<usize> = (0..self.rows.len()).collect();
+        Rendered {
+            table: self,
+            layout: self.fit_layout(self.natural_layout(), max_width),
+            rows: &rows,
+            grouping: None,
+        }
+        .to_string()
+    }
 
-        for (i, ((item, width), col)) in row
+    /// Renders the table to fit the current terminal, see [`terminal_width`].
+    ///
+    /// The table is rendered at its natural width when the terminal size is unknown.
+    pub fn render_for_terminal(&self) -> String {
+        match terminal_width() {
+            Some(width) => self.render_with_width(width),
+            None => self.to_string(),
+        }
+    }
+
+    fn natural_layout(&self) -> Layout {
+        let columns: Vec<usize> = (0..self.columns.len()).collect();
+        let rows: Vec<&Row> = self.rows.iter().collect();
+        let mut widths: Vec<usize> = self
+            .cell_widths
             .iter()
-            .zip(&self.column_widths)
-            .zip(&self.columns)
             .enumerate()
-        {
-            let formatted = col.format_content(item);
-            write!(f, "{:width$}", formatted, width = width)?;
+            .map(|(i, &content)| self.column_width(i, content, &rows))
+            .collect();
+        self.fit_group_titles(&columns, &mut widths);
+        Layout {
+            columns,
+            widths,
+            hidden: 0,
+            fixed: false,
+        }
+    }
 
-            if i < row.len() - 1 {
-                write!(f, "{}", chars.vertical)?;
+    // Natural layout of a subset of columns, sized for a subset of rows.
+    pub(crate) fn view_layout(&self, columns: &[usize], rows: &[usize]) -> Layout {
+        let mut widths: Vec<usize> = columns
+            .iter()
+            .map(|&i| self.measure_column(i, rows.iter().map(|&row| &self.rows[row])))
+            .collect();
+        self.fit_group_titles(columns, &mut widths);
+        Layout {
+            columns: columns.to_vec(),
+            widths,
+            hidden: 0,
+            fixed: false,
+        }
+    }
+
+    // Widens the visible `columns` so each header group title fits over them.
+    fn fit_group_titles(&self, columns: &[usize], widths: &mut [usize]) {
+        // Outer groups last, so they see the room their nested groups made.
+        for group in self.header_groups.iter().rev() {
+            let mut j = 0;
+            while j < columns.len() {
+                if !group.contains(columns[j]) {
+                    j += 1;
+                    continue;
+                }
+                let run = columns[j..]
+                    .iter()
+                    .take_while(|&&i| group.contains(i))
+                    .count();
+                let col = &self.columns[columns[j]];
+                widen(
+                    &mut widths[j..j + run],
+                    max_line_width(&group.title) + col.padding,
+                );
+                j += run;
             }
         }
+    }
 
-        writeln!(f, "{}", chars.vertical)
+    pub(crate) fn fit_layout(&self, natural: Layout, max_width: usize) -> Layout {
+        let mut widths = vec![0; self.columns.len()];
+        for (&i, &width) in natural.columns.iter().zip(&natural.widths) {
+            widths[i] = width;
+        }
+        let min_widths: Vec<usize> = self
+            .columns
+            .iter()
+            .zip(&widths)
+            .map(|(col, width)| col.total_width().min(*width))
+            .collect();
+        // Each visible column costs its width plus one border glyph. The frame needs
+        // one more on the right; without it, the leftmost glyph goes too.
+        let frame = self.separators.frame;
+        let total = |columns: &[usize], widths: &dyn Fn(usize) -> usize| {
+            let inner = columns.iter().map(|&i| widths(i) + 1).sum::<usize>();
+            if frame {
+                inner + 1
+            } else {
+                inner.saturating_sub(1)
+            }
+        };
+        let fits = |columns: &[usize], widths: &dyn Fn(usize) -> usize| {
+            total(columns, widths) <= max_width
+        };
+
+        // Least important columns first; among equals, the rightmost goes first.
+        let mut by_priority = natural.columns.clone();
+        by_priority.sort_by_key(|&i| (self.columns[i].priority, std::cmp::Reverse(i)));
+
+        let mut columns = natural.columns;
+        let mut hidden = natural.hidden;
+        for &i in &by_priority {
+            if columns.len() <= 1 || fits(&columns, &|i| min_widths[i]) {
+                break;
+            }
+            columns.retain(|&col| col != i);
+            hidden += 1;
+        }
+
+        for &i in by_priority.iter().filter(|i| columns.contains(i)) {
+            if fits(&columns, &|i| widths[i]) {
+                break;
+            }
+            let excess = total(&columns, &|i| widths[i]) - max_width;
+            widths[i] = widths[i].saturating_sub(excess).max(min_widths[i]);
+        }
+
+        Layout {
+            widths: columns.iter().map(|&i| widths[i]).collect(),
+            columns,
+            hidden,
+            fixed: true,
+        }
     }
 
-    fn write_horizontal_border(
+    pub(crate) fn render(
         &self,
         f: &mut fmt::Formatter<'_>,
-        left: char,
-        right: char,
-        horizontal: char,
-        separator: char,
+        layout: &Layout,
+        rows: &[usize],
+        grouping: Option<Grouping>,
     ) -> fmt::Result {
-        write!(f, "{}", left)?;
+        let columns = &layout.columns;
+        let stats = self.column_stats(rows);
+
+        let mut grid = self.header_rows(columns);
+        let header_rows = grid.len();
+        match grouping {
+            Some(grouping) => {
+                for (key, group) in grouping.split(self, rows) {
+                    self.push_group(&mut grid, layout, &stats, grouping, &key, &group);
+                }
+            }
+            None => grid.extend(self.body_rows(columns, rows, &stats, grid.len())),
+        }
+        if self.has_footer(columns) {
+            grid.push(GridRow::single(
+                columns
+                    .iter()
+                    .copied()
+                    .zip(self.footer_cells(columns, rows)),
+                true,
+            ));
+        }
+
+        GridRenderer {
+            columns: &self.columns,
+            chars: self.chars,
+            border_color: self.border_color,
+            separators: self.separators,
+            header_rows,
+        }
+        .render(f, &grid, &layout.widths, !layout.fixed)?;
+
+        match layout.hidden {
+            0 => Ok(()),
+            1 => writeln!(f, "+1 hidden column"),
+            n => writeln!(f, "+{} hidden columns", n),
+        }
+    }
+
+    // The header, data and footer rows of a view with their merged cells resolved,
+    // for exports that keep them.
+    pub(crate) fn export_rows(
+        &self,
+        columns: &[usize],
+        rows: &[usize],
+    ) -> (Vec<GridRow>, Vec<GridRow>, Option<GridRow>) {
+        let stats = self.column_stats(rows);
+        let header = self.header_rows(columns);
+        let body = self.body_rows(columns, rows, &stats, header.len());
+        let footer = self.has_footer(columns).then(|| {
+            GridRow::single(
+                columns
+                    .iter()
+                    .copied()
+                    .zip(self.footer_cells(columns, rows)),
+                true,
+            )
+        });
+        (header, body, footer)
+    }
 
-        for (i, width) in self.column_widths.iter().enumerate() {
-            write!(f, "{}", horizontal.to_string().repeat(*width))?;
-            if i < self.column_widths.len() - 1 {
-                write!(f, "{}", separator)?;
+    // Appends the title row, data rows and subtotal row of one group.
+    fn push_group(
+        &self,
+        grid: &mut Vec<GridRow>,
+        layout: &Layout,
+        stats: &[ColumnStats],
+        grouping: Grouping,
+        key: &str,
+        rows: &[usize],
+    ) {
+        let columns = &layout.columns;
+        let col = &self.columns[grouping.column];
+        // Without visible columns there is no cell to hold the title.
+        if let Some(&first) = columns.first() {
+            let mut title = SlotGrid::new(1, columns.len(), grid.len());
+            title.place(
+                0,
+                0,
+                Anchor {
+                    view: CellView {
+                        text: format!("{}: {}", col.header, key),
+                        alignment: Alignment::Left,
+                        style: col.header_style,
+                    },
+                    column: first,
+                    rowspan: 1,
+                    colspan: columns.len(),
+                },
+            );
+            grid.extend(title.into_rows(|_| true));
+        }
+
+        let start = grid.len();
+        grid.extend(self.body_rows(columns, rows, stats, start));
+
+        // Collapsing merges the grouping cells, unless other merged cells are in the way.
+        let position = columns.iter().position(|&i| i == grouping.column);
+        if let (true, Some(j)) = (grouping.collapse, position) {
+            let body = &mut grid[start..];
+            let unmerged = body.iter().all(|row| {
+                matches!(&row.slots[j], Slot::Anchor(anchor) if anchor.rowspan == 1 && anchor.colspan == 1)
+            });
+            let rowspan = body.len();
+            if unmerged {
+                for row in &mut body[1..] {
+                    row.slots[j] = Slot::Covered { row: start, col: j };
+                }
+                if let Slot::Anchor(anchor) = &mut body[0].slots[j] {
+                    anchor.rowspan = rowspan;
+                }
             }
         }
 
-        writeln!(f, "{}", right)
+        if grouping.subtotals {
+            grid.push(GridRow::single(
+                columns
+                    .iter()
+                    .copied()
+                    .zip(self.subtotal_cells(columns, rows)),
+                true,
+            ));
+        }
+    }
+
+    // Grid rows for the data rows at `rows`, resolving their merged cells. Spans are
+    // cut short where a column is hidden or a row is filtered out or sorted away.
+    fn body_rows(
+        &self,
+        columns: &[usize],
+        rows: &[usize],
+        stats: &[ColumnStats],
+        base: usize,
+    ) -> Vec<GridRow> {
+        let n = columns.len();
+        let mut grid = SlotGrid::new(rows.len(), n, base);
+
+        for (p, &index) in rows.iter().enumerate() {
+            let row = &self.rows[index];
+            let mut views: Vec<Option<CellView>> =
+                self.row_cells(row, stats).into_iter().map(Some).collect();
+
+            for j in 0..n {
+                if !grid.is_free(p, j) {
+                    continue;
+                }
+                let column = columns[j];
+                let (rowspan, colspan) = match row.span_at(column) {
+                    Some(span) => {
+                        let end = column.saturating_add(span.cols);
+                        let colspan = 1
+                            + (j + 1..n)
+                                .take_while(|&k| {
+                                    columns[k] > columns[k - 1]
+                                        && columns[k] < end
+                                        && grid.is_free(p, k)
+                                })
+                                .count();
+                        let rowspan = 1
+                            + (p + 1..rows.len())
+                                .take_while(|&q| {
+                                    q - p < span.rows
+                                        && rows[q] == index + (q - p)
+                                        && (j..j + colspan).all(|k| grid.is_free(q, k))
+                                })
+                                .count();
+                        (rowspan, colspan)
+                    }
+                    None => (1, 1),
+                };
+
+                grid.place(
+                    p,
+                    j,
+                    Anchor {
+                        view: views[column].take().expect("each column is drawn once"),
+                        column,
+                        rowspan,
+                        colspan,
+                    },
+                );
+            }
+        }
+
+        let separators = &self.separators;
+        grid.into_rows(|p| match p {
+            0 => separators.header_line,
+            p => separators.row_lines.before(p),
+        })
+    }
+}
+
+/// Which columns are drawn, and how wide, for one rendering of a table.
+pub(crate) struct Layout {
+    columns: Vec<usize>,
+    widths: Vec<usize>,
+    hidden: usize,
+    /// Set once the widths are fitted to a maximum, so merged cells may not widen
+    /// their columns.
+    fixed: bool,
+}
+
+pub(crate) struct Rendered<'a> {
+    pub(crate) table: &'a Table,
+    pub(crate) layout: Layout,
+    pub(crate) rows: &'a [usize],
+    pub(crate) grouping: Option<Grouping>,
+}
+
+impl fmt::Display for Rendered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.table.render(f, &self.layout, self.rows, self.grouping)
     }
 }
 
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let chars = self.style.get_chars();
-
-        self.write_horizontal_border(
-            f,
-            chars.top_left,
-            chars.top_right,
-            chars.horizontal,
-            chars.top_separator,
-        )?;
-
-        let headers: Vec<String> = self.columns.iter().map(|col| col.header.clone()).collect();
-        self.write_row(f, &headers, &chars)?;
-
-        self.write_horizontal_border(
-            f,
-            chars.left_separator,
-            chars.right_separator,
-            chars.horizontal,
-            chars.cross,
-        )?;
-
-        for row in &self.rows {
-            self.write_row(f, row, &chars)?;
-        }
-
-        self.write_horizontal_border(
-            f,
-            chars.bottom_left,
-            chars.bottom_right,
-            chars.horizontal,
-            chars.bottom_separator,
-        )
+        let rows: Vec<usize> = (0..self.rows.len()).collect();
+        self.render(f, &self.natural_layout(), &rows, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = ["A", "B", "C"]
+            .iter()
+            .fold(Table::builder(), |builder, &name| {
+                builder.add_column(Column::new(name))
+            })
+            .build();
+        table.add_row(vec!["a0", "bb0", "c0"]);
+        table.add_row(vec!["a1-long", "b1", "c1"]);
+        table.add_row(vec!["a2", "b2", "c2-longer"]);
+        table
+    }
+
+    // The running widths must match measuring every cell again.
+    fn check(table: &mut Table, expected: &[usize]) {
+        assert_eq!(table.cell_widths, expected);
+        table.calculate_column_widths();
+        assert_eq!(table.cell_widths, expected);
+    }
+
+    #[test]
+    fn set_border_style_replaces_the_glyphs() {
+        let mut t = table();
+        t.set_border_style(BorderStyle::Double);
+        assert!(t.to_string().starts_with('╔'));
+    }
+
+    #[test]
+    fn with_capacity_reserves_rows() {
+        let mut t = Table::with_capacity([Column::new("A"), Column::new("B")], 100);
+        assert!(t.rows.capacity() >= 100);
+        assert_eq!(t.columns.len(), 2);
+        t.add_row(vec!["a", "bb"]);
+        check(&mut t, &[1, 2]);
+    }
+
+    #[test]
+    fn cell_widths_follow_row_changes() {
+        let mut t = table();
+        check(&mut t, &[7, 3, 9]);
+
+        t.merge_cells(0, 0, 1, 2);
+        check(&mut t, &[7, 2, 9]);
+
+        t.insert_row(1, vec!["x", "bbbbbb", "x"]);
+        check(&mut t, &[7, 6, 9]);
+
+        assert!(t.remove_row(1).is_some());
+        check(&mut t, &[7, 2, 9]);
+
+        t.retain(|row| row[0] != Value::from("a1-long"));
+        check(&mut t, &[2, 2, 9]);
+    }
+
+    #[test]
+    fn cell_widths_follow_column_changes() {
+        let mut t = table();
+        t.merge_cells(0, 0, 1, 2);
+
+        // Split apart, so "a0" and the cleared cell count on their own.
+        t.move_column(0, 2);
+        check(&mut t, &[2, 9, 7]);
+        assert_eq!(
+            t.rows[0].cells,
+            [Value::Empty, Value::from("c0"), Value::from("a0")]
+        );
+
+        let mut t = table();
+        t.merge_cells(0, 0, 1, 2);
+        t.move_column(2, 0);
+        check(&mut t, &[9, 7, 2]);
+        assert_eq!(
+            t.rows[0].cells,
+            [Value::from("c0"), Value::from("a0"), Value::Empty]
+        );
+
+        assert!(t.remove_column(1).is_some());
+        check(&mut t, &[9, 2]);
+        assert_eq!(t.rows[0].cells, ["c0", "a0"].map(Value::from));
     }
 }
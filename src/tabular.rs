@@ -0,0 +1,30 @@
+use crate::{column::Column, table::Table, value::Value};
+
+/// A type whose values are rows of a table with a fixed set of columns.
+///
+/// Usually derived with `#[derive(Tabular)]` from the `derive` feature, which makes
+/// a column of every named field, see the `pinax-derive` crate for its attributes.
+pub trait Tabular {
+    /// The columns of the table, in the order of the cells of [`to_row`](Self::to_row).
+    fn columns() -> Vec<Column>;
+
+    fn to_row(&self) -> Vec<Value>;
+}
+
+impl Table {
+    /// Builds a table with the columns of `T` and a row for each of `rows`.
+    ///
+    /// ```text
+    /// let table = Table::from_rows(&predictions);
+    /// ```
+    pub fn from_rows<'a, T: Tabular + 'a>(rows: impl IntoIterator<Item = &'a T>) -> Table {
+        let mut table = T::columns()
+            .into_iter()
+            .fold(Table::builder(), |builder, column| {
+                builder.add_column(column)
+            })
+            .build();
+        table.add_rows(rows.into_iter().map(T::to_row));
+        table
+    }
+}
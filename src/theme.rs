@@ -0,0 +1,70 @@
+use crate::{
+    border::BorderChars,
+    separator::{RowLines, SeparatorPolicy},
+};
+
+/// A preset look for a [`Table`](crate::Table) or [`Grid`](crate::Grid): the border
+/// glyphs together with the lines that are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    /// `+`, `-` and `|` only, for logs and terminals without UTF-8.
+    Ascii,
+    /// Heavy box-drawing lines.
+    Heavy,
+    /// A Markdown pipe table.
+    Markdown,
+    /// A reStructuredText grid table, with a line between every pair of rows.
+    RstGrid,
+    /// A reStructuredText simple table, columns separated by spaces.
+    RstSimple,
+    /// Like the output of `psql`, with no frame.
+    Psql,
+    /// Like the output of the `mysql` client.
+    Mysql,
+    /// No visible lines, only whitespace between columns and below the header.
+    Blank,
+    /// Dotted lines.
+    Dots,
+}
+
+impl Theme {
+    pub(crate) fn chars(&self) -> BorderChars {
+        match self {
+            Self::Ascii | Self::Psql | Self::Mysql => BorderChars::uniform('-', '|', '+'),
+            Self::Heavy => BorderChars::single_weight(
+                ['┏', '┓', '┗', '┛'],
+                '━',
+                '┃',
+                ['┳', '┻', '┣', '┫'],
+                '╋',
+            ),
+            Self::Markdown => BorderChars::uniform('-', '|', '|'),
+            Self::RstGrid => BorderChars {
+                header_horizontal: '=',
+                ..BorderChars::uniform('-', '|', '+')
+            },
+            Self::RstSimple => BorderChars::uniform('=', ' ', ' '),
+            Self::Blank => BorderChars::uniform(' ', ' ', ' '),
+            Self::Dots => BorderChars {
+                top_left: '.',
+                top_right: '.',
+                top_separator: '.',
+                ..BorderChars::uniform('.', ':', ':')
+            },
+        }
+    }
+
+    /// The lines the theme draws. Tables and grids built with a theme can still
+    /// override them with their own `separators`.
+    pub fn separators(&self) -> SeparatorPolicy {
+        let policy = SeparatorPolicy::default();
+        match self {
+            Self::Ascii | Self::Heavy | Self::Mysql | Self::Dots => policy,
+            Self::Markdown => policy.with_outer_rules(false),
+            Self::RstGrid => policy.with_row_lines(RowLines::All),
+            Self::RstSimple => policy.with_frame(false).with_outer_rules(true),
+            Self::Psql => policy.with_frame(false),
+            Self::Blank => policy.with_frame(false),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::width::{display_width, grapheme_width, graphemes, is_escape_sequence};
+
+/// Marker inserted where text was cut off.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Ellipsis {
+    /// Three ASCII dots, `...`.
+    #[default]
+    Ascii,
+    /// The single-column Unicode ellipsis, `…`.
+    Unicode,
+    /// Cut the text without a marker.
+    None,
+}
+
+impl Ellipsis {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ascii => "...",
+            Self::Unicode => "…",
+            Self::None => "",
+        }
+    }
+}
+
+/// Which part of the text is dropped when it is too wide.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TruncatePosition {
+    /// Keep the end of the text, e.g. `...ort.txt`.
+    Start,
+    /// Keep both ends of the text, e.g. `/usr/...ort.txt`.
+    Middle,
+    /// Keep the beginning of the text, e.g. `/usr/sha...`.
+    #[default]
+    End,
+}
+
+/// How text that does not fit its width is shortened.
+///
+/// Truncation never splits a grapheme cluster, so accented letters, Hangul syllables
+/// and emoji sequences are either kept whole or dropped whole. ANSI escape sequences in
+/// the dropped part are kept, so styled text still resets its colors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Truncation {
+    position: TruncatePosition,
+    ellipsis: Ellipsis,
+}
+
+impl Truncation {
+    pub fn new(position: TruncatePosition) -> Self {
+        Self {
+            position,
+            ellipsis: Ellipsis::default(),
+        }
+    }
+
+    pub fn with_ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Shortens `text` so its display width is at most `width` columns.
+    pub(crate) fn ellipsis_width(&self) -> usize {
+        display_width(self.ellipsis.as_str())
+    }
+
+    pub fn truncate(&self, text: &str, width: usize) -> String {
+        if display_width(text) <= width {
+            return text.to_string();
+        }
+
+        // Drop the marker entirely rather than overflow when it cannot fit.
+        let ellipsis = self.ellipsis.as_str();
+        let ellipsis_width = display_width(ellipsis);
+        let (ellipsis, budget) = if ellipsis_width > width {
+            ("", width)
+        } else {
+            (ellipsis, width - ellipsis_width)
+        };
+
+        match self.position {
+            TruncatePosition::End => {
+                let head = take_head(text, budget);
+                let dropped = escapes(&text[head.len()..]);
+                format!("{}{}{}", head, ellipsis, dropped)
+            }
+            TruncatePosition::Start => {
+                let tail = take_tail(text, budget);
+                let dropped = escapes(&text[..text.len() - tail.len()]);
+                format!("{}{}{}", dropped, ellipsis, tail)
+            }
+            TruncatePosition::Middle => {
+                let head = take_head(text, budget.div_ceil(2));
+                let tail = take_tail(text, budget - display_width(head));
+                let dropped = escapes(&text[head.len()..text.len() - tail.len()]);
+                format!("{}{}{}{}", head, ellipsis, dropped, tail)
+            }
+        }
+    }
+}
+
+fn take_head(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    let mut end = 0;
+    for grapheme in graphemes(text) {
+        used += grapheme_width(grapheme);
+        if used > width {
+            break;
+        }
+        end += grapheme.len();
+    }
+    &text[..end]
+}
+
+fn take_tail(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    let mut start = text.len();
+    for grapheme in graphemes(text).collect::<Vec<_>>().into_iter().rev() {
+        used += grapheme_width(grapheme);
+        if used > width {
+            break;
+        }
+        start -= grapheme.len();
+    }
+    &text[start..]
+}
+
+// The escape sequences in `text`, in order, without the text they apply to.
+fn escapes(text: &str) -> String {
+    graphemes(text).filter(|g| is_escape_sequence(g)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: &str = "\x1b[31m";
+    const RESET: &str = "\x1b[0m";
+
+    fn truncate(position: TruncatePosition, text: &str, width: usize) -> String {
+        Truncation::new(position).truncate(text, width)
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(truncate(TruncatePosition::End, "abcdefgh", 5), "ab...");
+        assert_eq!(truncate(TruncatePosition::Start, "abcdefgh", 5), "...gh");
+        assert_eq!(truncate(TruncatePosition::Middle, "abcdefgh", 6), "ab...h");
+        assert_eq!(truncate(TruncatePosition::End, "abc", 5), "abc");
+        assert_eq!(truncate(TruncatePosition::End, "abcdef", 2), "ab");
+    }
+
+    #[test]
+    fn never_splits_graphemes() {
+        let text = "日本語テキスト";
+        assert_eq!(truncate(TruncatePosition::End, text, 6), "日...");
+        let text = "e\u{0301}e\u{0301}e\u{0301}e\u{0301}e\u{0301}";
+        assert_eq!(
+            Truncation::new(TruncatePosition::End)
+                .with_ellipsis(Ellipsis::Unicode)
+                .truncate(text, 3),
+            "e\u{0301}e\u{0301}…"
+        );
+    }
+
+    #[test]
+    fn keeps_dropped_resets() {
+        let text = format!("{RED}abcdefgh{RESET}");
+        assert_eq!(
+            truncate(TruncatePosition::End, &text, 4),
+            format!("{RED}a...{RESET}")
+        );
+        assert_eq!(
+            truncate(TruncatePosition::Start, &text, 4),
+            format!("{RED}...h{RESET}")
+        );
+        assert_eq!(
+            truncate(TruncatePosition::Middle, &text, 5),
+            format!("{RED}a...h{RESET}")
+        );
+    }
+
+    #[test]
+    fn keeps_escapes_in_the_middle() {
+        let text = format!("ab{RED}cd{RESET}efgh");
+        assert_eq!(
+            truncate(TruncatePosition::Middle, &text, 7),
+            format!("ab{RED}...{RESET}gh")
+        );
+        assert_eq!(display_width(&truncate(TruncatePosition::End, &text, 5)), 5);
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt;
+use std::time::Duration;
+
+/// A calendar date without a time zone, displayed as `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Returns `None` if `month` or `day` is out of range for the given year.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days_in_month)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The content of a table cell.
+///
+/// Cells keep their type until the table is rendered, so columns can format numbers
+/// consistently and numeric cells are right-aligned by default.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Value {
+    #[default]
+    Empty,
+    Text(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    Date(Date),
+    Duration(Duration),
+}
+
+impl Value {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Empty => true,
+            Self::Text(text) => text.is_empty(),
+            _ => false,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Self::Integer(_) | Self::Float(_))
+    }
+
+    /// Returns the value as a number. Text is parsed, so cells that were added as
+    /// strings still take part in numeric comparisons.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(value) => Some(*value as f64),
+            Self::Float(value) => Some(*value),
+            Self::Text(text) => text.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => Ok(()),
+            Self::Text(text) => f.write_str(text),
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Float(value) => write!(f, "{}", value),
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::Date(date) => write!(f, "{}", date),
+            Self::Duration(duration) => write_duration(f, *duration),
+        }
+    }
+}
+
+fn write_duration(f: &mut fmt::Formatter<'_>, duration: Duration) -> fmt::Result {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        write!(f, "{}h{:02}m{:02}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        write!(f, "{}m{:02}s", minutes, seconds)
+    } else if secs > 0 {
+        write!(f, "{}.{:03}s", seconds, duration.subsec_millis())
+    } else if duration.subsec_millis() > 0 {
+        write!(f, "{}ms", duration.subsec_millis())
+    } else {
+        write!(f, "{}µs", duration.subsec_micros())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Self {
+        Self::Text(value.clone())
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Self::Integer(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, isize, u8, u16, u32);
+
+// Values beyond `i64::MAX` keep their magnitude as floats instead of wrapping.
+macro_rules! impl_from_wide_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    i64::try_from(value).map_or(Self::Float(value as f64), Self::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_wide_unsigned!(u64, usize);
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Self::Float(value as f64)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Date> for Value {
+    fn from(value: Date) -> Self {
+        Self::Date(value)
+    }
+}
+
+impl From<Duration> for Value {
+    fn from(value: Duration) -> Self {
+        Self::Duration(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Empty, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() {
+        assert_eq!(Date::new(2024, 2, 29).unwrap().to_string(), "2024-02-29");
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert_eq!(Date::new(1900, 2, 29), None);
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert_eq!(Date::new(2024, 4, 31), None);
+        assert_eq!(Date::new(2024, 13, 1), None);
+        assert_eq!(Date::new(2024, 1, 0), None);
+        assert!(Date::new(2024, 1, 31) < Date::new(2024, 2, 1));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Value::Empty.to_string(), "");
+        assert_eq!(Value::from("text").to_string(), "text");
+        assert_eq!(Value::from(-42).to_string(), "-42");
+        assert_eq!(Value::from(1.5).to_string(), "1.5");
+        assert_eq!(Value::from(2.0).to_string(), "2");
+        assert_eq!(Value::from(f64::NAN).to_string(), "NaN");
+        assert_eq!(Value::from(false).to_string(), "false");
+    }
+
+    #[test]
+    fn durations() {
+        let display = |duration| Value::from(duration).to_string();
+        assert_eq!(
+            display(Duration::from_secs(3 * 3600 + 5 * 60 + 7)),
+            "3h05m07s"
+        );
+        assert_eq!(display(Duration::from_secs(65)), "1m05s");
+        assert_eq!(display(Duration::from_millis(1500)), "1.500s");
+        assert_eq!(display(Duration::from_millis(250)), "250ms");
+        assert_eq!(display(Duration::from_micros(42)), "42µs");
+        assert_eq!(display(Duration::ZERO), "0µs");
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Value::from(3).as_f64(), Some(3.0));
+        assert_eq!(Value::from(2.5).as_f64(), Some(2.5));
+        assert_eq!(Value::from(" 7.25 ").as_f64(), Some(7.25));
+        assert_eq!(Value::from("seven").as_f64(), None);
+        assert_eq!(Value::from(true).as_f64(), None);
+        assert_eq!(Value::Empty.as_f64(), None);
+
+        assert!(Value::from(1).is_numeric());
+        assert!(Value::from(1.0).is_numeric());
+        assert!(!Value::from("1").is_numeric());
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Value::from(7u8), Value::Integer(7));
+        assert_eq!(Value::from(u32::MAX), Value::Integer(u32::MAX as i64));
+        assert_eq!(Value::from(i64::MAX as u64), Value::Integer(i64::MAX));
+        assert_eq!(Value::from(u64::MAX), Value::Float(u64::MAX as f64));
+        assert_eq!(Value::from(1.5f32), Value::Float(1.5));
+        assert_eq!(Value::from(String::from("a")), Value::Text("a".into()));
+        assert_eq!(Value::from(None::<i32>), Value::Empty);
+        assert_eq!(Value::from(Some(4)), Value::Integer(4));
+    }
+
+    #[test]
+    fn emptiness() {
+        assert!(Value::Empty.is_empty());
+        assert!(Value::from("").is_empty());
+        assert!(!Value::from(" ").is_empty());
+        assert!(!Value::from(0).is_empty());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    export::{self, CsvOptions},
+    sort::ColumnKey,
+    table::{Rendered, Table},
+    value::Value,
+};
+use std::ops::{Bound, RangeBounds};
+use std::{fmt, io};
+
+/// A filtered and projected view of a [`Table`].
+///
+/// Views only hold row and column indices into the table they were created from, and
+/// render through `Display` like the table itself. Every operation narrows the view
+/// further, so they can be chained:
+///
+/// ```text
+/// table.filter(|row| row[3] == Value::from("failed")).select_columns(["Name", "Status"]).head(10)
+/// ```
+#[derive(Clone)]
+pub struct TableView<'a> {
+    pub(crate) table: &'a Table,
+    pub(crate) rows: Vec<usize>,
+    pub(crate) columns: Vec<usize>,
+}
+
+impl<'a> TableView<'a> {
+    pub(crate) fn new(table: &'a Table) -> Self {
+        Self {
+            table,
+            rows: (0..table.rows.len()).collect(),
+            columns: (0..table.columns.len()).collect(),
+        }
+    }
+
+    /// Keeps the rows for which `predicate` returns `true`. The predicate sees every
+    /// cell of the row, including cells of columns that are not selected.
+    pub fn filter(mut self, predicate: impl Fn(&[Value]) -> bool) -> Self {
+        let table = self.table;
+        self.rows.retain(|&row| predicate(&table.rows[row].cells));
+        self
+    }
+
+    /// Keeps the rows whose cell in `column` is displayed as exactly `text`, as with
+    /// a `--where status=failed` flag. Unknown columns match no rows.
+    pub fn where_eq(mut self, column: impl Into<ColumnKey>, text: &str) -> Self {
+        let table = self.table;
+        match table.column_index(&column.into()) {
+            Some(index) => {
+                let col = &table.columns[index];
+                self.rows.retain(|&row| {
+                    let value = table.rows[row].cells.get(index).unwrap_or(&Value::Empty);
+                    col.format_value(value) == text
+                });
+            }
+            None => self.rows.clear(),
+        }
+        self
+    }
+
+    /// Shows only the given columns, in the given order. Unknown columns are skipped,
+    /// and a column given more than once is shown at its first position.
+    pub fn select_columns<K: Into<ColumnKey>>(
+        mut self,
+        columns: impl IntoIterator<Item = K>,
+    ) -> Self {
+        let table = self.table;
+        self.columns.clear();
+        for index in columns
+            .into_iter()
+            .filter_map(|key| table.column_index(&key.into()))
+        {
+            if !self.columns.contains(&index) {
+                self.columns.push(index);
+            }
+        }
+        self
+    }
+
+    pub fn head(mut self, n: usize) -> Self {
+        self.rows.truncate(n);
+        self
+    }
+
+    pub fn tail(mut self, n: usize) -> Self {
+        let skip = self.rows.len().saturating_sub(n);
+        self.rows.drain(..skip);
+        self
+    }
+
+    /// Keeps the rows at the given positions within the view. Out of range bounds
+    /// are clamped.
+    pub fn slice(mut self, range: impl RangeBounds<usize>) -> Self {
+        let len = self.rows.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        }
+        .min(len);
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        }
+        .clamp(start, len);
+
+        self.rows.truncate(end);
+        self.rows.drain(..start);
+        self
+    }
+
+    /// Number of data rows in the view.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Writes the view as a Markdown table, see [`Table::to_markdown`].
+    pub fn to_markdown(&self) -> String {
+        export::to_markdown(self.table, &self.rows, &self.columns)
+    }
+
+    /// Writes the view as an HTML table, see [`Table::to_html`].
+    pub fn to_html(&self) -> String {
+        export::to_html(self.table, &self.rows, &self.columns)
+    }
+
+    /// Writes the view as a JSON array, see [`Table::to_json`].
+    pub fn to_json(&self) -> String {
+        export::to_json(self.table, &self.rows, &self.columns)
+    }
+
+    /// Writes the view as newline-delimited JSON, see [`Table::to_ndjson`].
+    pub fn to_ndjson(&self, writer: impl io::Write) -> io::Result<()> {
+        export::write_ndjson(self.table, &self.rows, &self.columns, writer)
+    }
+
+    /// Writes the view as CSV, see [`Table::to_csv`].
+    pub fn to_csv(&self, writer: impl io::Write, options: &CsvOptions) -> io::Result<()> {
+        export::write_csv(self.table, &self.rows, &self.columns, writer, options)
+    }
+
+    /// Groups the rows of the view by their cell in `column`, see [`GroupedView`].
+    pub fn group_by(self, column: impl Into<ColumnKey>) -> GroupedView<'a> {
+        let column = self.table.column_index(&column.into());
+        GroupedView {
+            view: self,
+            grouping: column.map(|column| Grouping {
+                column,
+                subtotals: false,
+                collapse: false,
+            }),
+        }
+    }
+
+    /// Renders the view so that it is at most `max_width` columns wide, see
+    /// [`Table::render_with_width`].
+    pub fn render_with_width(&self, max_width: usize) -> String {
+        self.render_fitted(max_width, None)
+    }
+
+    fn render_fitted(&self, max_width: usize, grouping: Option<Grouping>) -> String {
+        let layout = self.table.view_layout(&self.columns, &self.rows);
+        Rendered {
+            table: self.table,
+            layout: self.table.fit_layout(layout, max_width),
+            rows: &self.rows,
+            grouping,
+        }
+        .to_string()
+    }
+
+    fn render(&self, f: &mut fmt::Formatter<'_>, grouping: Option<Grouping>) -> fmt::Result {
+        let layout = self.table.view_layout(&self.columns, &self.rows);
+        self.table.render(f, &layout, &self.rows, grouping)
+    }
+}
+
+impl fmt::Display for TableView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, None)
+    }
+}
+
+/// How the data rows of a table are split into groups.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Grouping {
+    pub(crate) column: usize,
+    pub(crate) subtotals: bool,
+    pub(crate) collapse: bool,
+}
+
+impl Grouping {
+    // Splits `rows` by the displayed value of the grouping column. Groups appear in
+    // the order their first row does, and keep the order of their rows.
+    pub(crate) fn split(&self, table: &Table, rows: &[usize]) -> Vec<(String, Vec<usize>)> {
+        let col = &table.columns[self.column];
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for &row in rows {
+            let value = table.rows[row]
+                .cells
+                .get(self.column)
+                .unwrap_or(&Value::Empty);
+            let key = col.format_value(value);
+            match groups.iter_mut().find(|(existing, _)| *existing == key) {
+                Some((_, members)) => members.push(row),
+                None => groups.push((key, vec![row])),
+            }
+        }
+        groups
+    }
+}
+
+/// Rows of a [`TableView`] grouped by the value of one column.
+///
+/// Each group is drawn under a full-width title row naming the column and value.
+/// Groups are listed in the order of their first row, so sort the table first to
+/// get them in order. An unknown grouping column renders the rows ungrouped.
+#[derive(Clone)]
+pub struct GroupedView<'a> {
+    view: TableView<'a>,
+    grouping: Option<Grouping>,
+}
+
+impl GroupedView<'_> {
+    /// Adds a row below each group with the column aggregates computed over the
+    /// group, see [`Column::with_aggregate`](crate::Column::with_aggregate).
+    pub fn with_subtotals(mut self, enabled: bool) -> Self {
+        if let Some(grouping) = &mut self.grouping {
+            grouping.subtotals = enabled;
+        }
+        self
+    }
+
+    /// Shows the grouping value once per group, in a cell merged across the rows of
+    /// the group, instead of repeating it on every row.
+    pub fn collapse_repeated(mut self, enabled: bool) -> Self {
+        if let Some(grouping) = &mut self.grouping {
+            grouping.collapse = enabled;
+        }
+        self
+    }
+
+    /// Renders the groups so that they are at most `max_width` columns wide, see
+    /// [`Table::render_with_width`].
+    pub fn render_with_width(&self, max_width: usize) -> String {
+        self.view.render_fitted(max_width, self.grouping)
+    }
+}
+
+impl fmt::Display for GroupedView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.view.render(f, self.grouping)
+    }
+}
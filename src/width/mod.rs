@@ -0,0 +1,186 @@
+mod tables;
+#[cfg(test)]
+mod tests;
+
+use tables::{EMOJI_VARIATION, WIDE, ZERO_WIDTH};
+
+const ZWJ: char = '\u{200D}';
+const VS15: char = '\u{FE0E}';
+const VS16: char = '\u{FE0F}';
+const KEYCAP: char = '\u{20E3}';
+
+fn in_table(table: &[(u32, u32)], ch: char) -> bool {
+    let cp = ch as u32;
+    table
+        .binary_search_by(|&(start, end)| {
+            if end < cp {
+                std::cmp::Ordering::Less
+            } else if start > cp {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+fn is_control(ch: char) -> bool {
+    matches!(ch as u32, 0x00..=0x1F | 0x7F..=0x9F)
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    matches!(ch as u32, 0x1F1E6..=0x1F1FF)
+}
+
+fn is_emoji_modifier(ch: char) -> bool {
+    matches!(ch as u32, 0x1F3FB..=0x1F3FF)
+}
+
+fn is_extender(ch: char) -> bool {
+    !is_control(ch) && in_table(ZERO_WIDTH, ch)
+}
+
+/// Returns the number of terminal columns a single code point occupies.
+///
+/// Control characters, combining marks and other zero width code points count as 0,
+/// East Asian Wide and Fullwidth characters count as 2, everything else counts as 1.
+pub fn char_width(ch: char) -> usize {
+    if is_control(ch) || in_table(ZERO_WIDTH, ch) {
+        0
+    } else if in_table(WIDE, ch) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the number of terminal columns `text` occupies.
+///
+/// Width is measured per grapheme cluster, so emoji ZWJ sequences, flags, skin tone
+/// modifiers and variation sequences are counted the way terminals draw them. ANSI
+/// escape sequences take up no space.
+pub fn display_width(text: &str) -> usize {
+    graphemes(text).map(grapheme_width).sum()
+}
+
+/// Returns the display width of the widest line in `text`.
+pub(crate) fn max_line_width(text: &str) -> usize {
+    text.lines().map(display_width).max().unwrap_or(0)
+}
+
+/// Returns the number of terminal columns a single grapheme cluster occupies.
+pub(crate) fn grapheme_width(grapheme: &str) -> usize {
+    let mut chars = grapheme.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+
+    // Only the leading code point of a cluster takes up space; the rest either
+    // combine with it or select its presentation.
+    let mut width = char_width(first);
+    for ch in chars {
+        match ch {
+            VS16 if in_table(EMOJI_VARIATION, first) => width = 2,
+            VS15 if in_table(EMOJI_VARIATION, first) => width = 1,
+            _ if is_emoji_modifier(ch) && in_table(EMOJI_VARIATION, first) => width = 2,
+            KEYCAP => width = 2,
+            _ if is_regional_indicator(first) && is_regional_indicator(ch) => width = 2,
+            _ => {}
+        }
+    }
+    width
+}
+
+/// Splits `text` into grapheme clusters.
+pub(crate) fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { text }
+}
+
+/// Iterator over the grapheme clusters of a string, see [`graphemes`].
+///
+/// This covers the cluster rules that matter for terminal layout: CR LF, combining
+/// marks and other extenders, emoji modifiers, ZWJ sequences and regional indicator
+/// pairs. ANSI escape sequences are yielded as single zero width clusters so styled
+/// text measures and truncates like plain text.
+pub(crate) struct Graphemes<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if let Some(len) = escape_sequence_len(self.text) {
+            let (sequence, rest) = self.text.split_at(len);
+            self.text = rest;
+            return Some(sequence);
+        }
+
+        let mut chars = self.text.char_indices().peekable();
+        let (_, first) = chars.next()?;
+        let mut end = first.len_utf8();
+        let mut prev = first;
+        let mut regional_pair = false;
+
+        while let Some(&(idx, ch)) = chars.peek() {
+            let joins = if prev == '\r' {
+                ch == '\n'
+            } else if is_control(prev) {
+                false
+            } else if prev == ZWJ {
+                !is_control(ch)
+            } else if is_regional_indicator(prev) && is_regional_indicator(ch) {
+                !regional_pair
+            } else {
+                is_extender(ch) || is_emoji_modifier(ch)
+            };
+
+            if !joins {
+                break;
+            }
+
+            regional_pair = is_regional_indicator(ch) && is_regional_indicator(prev);
+            end = idx + ch.len_utf8();
+            prev = ch;
+            chars.next();
+        }
+
+        let (grapheme, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(grapheme)
+    }
+}
+
+/// Returns whether `grapheme` is a whole CSI or OSC escape sequence.
+pub(crate) fn is_escape_sequence(grapheme: &str) -> bool {
+    escape_sequence_len(grapheme) == Some(grapheme.len())
+}
+
+// Length in bytes of the CSI or OSC escape sequence at the start of `text`, if any.
+fn escape_sequence_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&0x1B) {
+        return None;
+    }
+
+    match bytes.get(1) {
+        // CSI: parameters and intermediates, then a final byte in 0x40..=0x7E.
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7E).contains(b))
+            .map(|end| end + 3),
+        // OSC: terminated by BEL or ST (ESC \).
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return Some(i + 1),
+                    0x1B if bytes.get(i + 1) == Some(&b'\\') => return Some(i + 2),
+                    _ => i += 1,
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
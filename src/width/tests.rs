@@ -0,0 +1,196 @@
+use super::tables::{EMOJI_VARIATION, WIDE, ZERO_WIDTH};
+use super::{char_width, display_width, graphemes};
+
+// Emoji test data for UTS #51, Unicode 17.0.
+const EMOJI_TEST: &str = include_str!("../../tests/data/emoji-test.txt");
+
+fn split(text: &str) -> Vec<&str> {
+    graphemes(text).collect()
+}
+
+#[test]
+fn tables_are_sorted_and_disjoint() {
+    for table in [ZERO_WIDTH, WIDE, EMOJI_VARIATION] {
+        for &(start, end) in table {
+            assert!(start <= end, "{start:#X}..{end:#X}");
+        }
+        for pair in table.windows(2) {
+            assert!(
+                pair[0].1 < pair[1].0,
+                "{:#X} overlaps {:#X}",
+                pair[0].1,
+                pair[1].0
+            );
+        }
+    }
+}
+
+#[test]
+fn east_asian_wide_and_fullwidth() {
+    assert_eq!(char_width('中'), 2);
+    assert_eq!(char_width('한'), 2);
+    assert_eq!(char_width('\u{3000}'), 2);
+    assert_eq!(char_width('Ａ'), 2);
+    assert_eq!(char_width('ｱ'), 1);
+    assert_eq!(char_width('a'), 1);
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("Ｒｕｓｔ"), 8);
+    assert_eq!(display_width("abc中"), 5);
+}
+
+#[test]
+fn control_characters_take_no_space() {
+    assert_eq!(char_width('\0'), 0);
+    assert_eq!(char_width('\u{7F}'), 0);
+    assert_eq!(char_width('\u{85}'), 0);
+    assert_eq!(display_width("a\tb"), 2);
+}
+
+#[test]
+fn combining_marks() {
+    assert_eq!(char_width('\u{0301}'), 0);
+    assert_eq!(char_width('\u{20DD}'), 0);
+    assert_eq!(display_width("e\u{0301}"), 1);
+    assert_eq!(split("e\u{0301}x"), ["e\u{0301}", "x"]);
+    assert_eq!(display_width("Z\u{0324}\u{0308}a\u{0308}"), 2);
+    assert_eq!(display_width("\u{0928}\u{093F}"), 2);
+    // Conjoining Hangul jamo make up a single wide syllable.
+    assert_eq!(display_width("\u{1100}\u{1161}\u{11A8}"), 2);
+    assert_eq!(
+        split("\u{1100}\u{1161}\u{11A8}"),
+        ["\u{1100}\u{1161}\u{11A8}"]
+    );
+}
+
+#[test]
+fn zwj_sequences() {
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    assert_eq!(split(family), [family]);
+    assert_eq!(display_width(family), 2);
+
+    let rainbow_flag = "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}";
+    assert_eq!(split(rainbow_flag), [rainbow_flag]);
+    assert_eq!(display_width(rainbow_flag), 2);
+
+    assert_eq!(display_width("a\u{200D}b"), 1);
+}
+
+#[test]
+fn variation_selectors() {
+    assert_eq!(display_width("\u{2764}"), 1);
+    assert_eq!(display_width("\u{2764}\u{FE0F}"), 2);
+    assert_eq!(display_width("\u{2764}\u{FE0E}"), 1);
+    assert_eq!(display_width("\u{260E}\u{FE0F}"), 2);
+    assert_eq!(display_width("\u{231A}"), 2);
+    assert_eq!(display_width("\u{231A}\u{FE0F}"), 2);
+    // Selectors only change the presentation of characters that have both.
+    assert_eq!(display_width("a\u{FE0F}"), 1);
+}
+
+#[test]
+fn keycaps() {
+    assert_eq!(split("1\u{FE0F}\u{20E3}2"), ["1\u{FE0F}\u{20E3}", "2"]);
+    assert_eq!(display_width("1\u{FE0F}\u{20E3}"), 2);
+    assert_eq!(display_width("#\u{20E3}"), 2);
+    assert_eq!(display_width("*\u{FE0F}\u{20E3}"), 2);
+}
+
+#[test]
+fn flags() {
+    let japan = "\u{1F1EF}\u{1F1F5}";
+    let usa = "\u{1F1FA}\u{1F1F8}";
+    assert_eq!(display_width(japan), 2);
+    assert_eq!(split(&format!("{japan}{usa}")), [japan, usa]);
+    assert_eq!(display_width(&format!("{japan}{usa}")), 4);
+    // A lone regional indicator is drawn as a letter.
+    assert_eq!(split("\u{1F1EF}\u{1F1F5}\u{1F1FA}"), [japan, "\u{1F1FA}"]);
+    assert_eq!(display_width("\u{1F1FA}"), 1);
+
+    let england = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}";
+    assert_eq!(split(england), [england]);
+    assert_eq!(display_width(england), 2);
+}
+
+#[test]
+fn skin_tones() {
+    let thumbs_up = "\u{1F44D}\u{1F3FD}";
+    assert_eq!(split(thumbs_up), [thumbs_up]);
+    assert_eq!(display_width(thumbs_up), 2);
+    assert_eq!(display_width("\u{1F3FD}"), 2);
+    // Modifiers give text style characters an emoji presentation.
+    assert_eq!(display_width("\u{270C}\u{1F3FB}"), 2);
+
+    let handshake = "\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FF}";
+    assert_eq!(split(handshake), [handshake]);
+    assert_eq!(display_width(handshake), 2);
+}
+
+#[test]
+fn crlf_is_one_grapheme() {
+    assert_eq!(split("a\r\nb"), ["a", "\r\n", "b"]);
+    assert_eq!(split("\r\r\n"), ["\r", "\r\n"]);
+}
+
+#[test]
+fn csi_sequences_are_zero_width_graphemes() {
+    let text = "\x1b[1;31mab\x1b[0m";
+    assert_eq!(split(text), ["\x1b[1;31m", "a", "b", "\x1b[0m"]);
+    assert_eq!(display_width(text), 2);
+    assert_eq!(display_width("\x1b[38;2;255;136;0m中\x1b[0m"), 2);
+    // Marks after an escape do not join onto it.
+    assert_eq!(split("\x1b[0m\u{0301}"), ["\x1b[0m", "\u{0301}"]);
+}
+
+#[test]
+fn osc_sequences_are_zero_width_graphemes() {
+    let bel = "\x1b]8;;https://example.com\x07link\x1b]8;;\x07";
+    assert_eq!(
+        split(bel),
+        [
+            "\x1b]8;;https://example.com\x07",
+            "l",
+            "i",
+            "n",
+            "k",
+            "\x1b]8;;\x07"
+        ]
+    );
+    assert_eq!(display_width(bel), 4);
+
+    let st = "\x1b]0;title\x1b\\ok";
+    assert_eq!(split(st), ["\x1b]0;title\x1b\\", "o", "k"]);
+    assert_eq!(display_width(st), 2);
+}
+
+#[test]
+fn unterminated_escapes_are_plain_characters() {
+    assert_eq!(split("\x1b[31"), ["\x1b", "[", "3", "1"]);
+    assert_eq!(display_width("\x1b[31"), 3);
+    assert_eq!(
+        split("\x1b]0;title"),
+        ["\x1b", "]", "0", ";", "t", "i", "t", "l", "e"]
+    );
+}
+
+#[test]
+fn emoji_test_conformance() {
+    let mut checked = 0;
+    for line in EMOJI_TEST.lines() {
+        let Some((points, rest)) = line.split_once(';') else {
+            continue;
+        };
+        let status = rest.split('#').next().unwrap_or_default().trim();
+        if line.starts_with('#') || !matches!(status, "fully-qualified" | "component") {
+            continue;
+        }
+
+        let emoji: String = points
+            .split_whitespace()
+            .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+            .collect();
+        assert_eq!(split(&emoji), [emoji.as_str()], "{}", line);
+        assert_eq!(display_width(&emoji), 2, "{}", line);
+        checked += 1;
+    }
+    assert!(checked > 3000, "only {checked} emoji checked");
+}
@@ -0,0 +1,139 @@
+use crate::width::{display_width, grapheme_width, graphemes, is_escape_sequence};
+
+/// Word-wraps `text` into lines of at most `max_width` display columns.
+///
+/// Embedded newlines always start a new line and blank lines are kept. Words wider
+/// than `max_width` are broken between grapheme clusters. ANSI colors that are still
+/// set at the end of a line are reset there and set again on the next line.
+pub(crate) fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
+    let max_width = max_width.max(1);
+    let lines = text
+        .lines()
+        .flat_map(|line| {
+            let mut wrapped = Vec::new();
+            let mut current = String::new();
+            let mut current_width = 0;
+
+            for word in line.split_whitespace() {
+                let word_width = display_width(word);
+                let space_width = if current_width > 0 { 1 } else { 0 };
+
+                if current_width + word_width + space_width <= max_width {
+                    if current_width > 0 {
+                        current.push(' ');
+                        current_width += 1;
+                    }
+                    current.push_str(word);
+                    current_width += word_width;
+                } else {
+                    if !current.is_empty() {
+                        wrapped.push(current);
+                    }
+                    current = String::new();
+                    current_width = 0;
+
+                    // Split words that cannot fit on a line of their own.
+                    for grapheme in graphemes(word) {
+                        let width = grapheme_width(grapheme);
+                        if current_width + width > max_width && !current.is_empty() {
+                            wrapped.push(std::mem::take(&mut current));
+                            current_width = 0;
+                        }
+                        current.push_str(grapheme);
+                        current_width += width;
+                    }
+                }
+            }
+            if !current.is_empty() || wrapped.is_empty() {
+                wrapped.push(current);
+            }
+            wrapped
+        })
+        .collect();
+    carry_styles(lines)
+}
+
+// Closes the SGR sequences that are in effect at the end of each line and reopens
+// them at the start of the next, so every line can be drawn on its own.
+fn carry_styles(lines: Vec<String>) -> Vec<String> {
+    let mut active = String::new();
+    lines
+        .into_iter()
+        .map(|line| {
+            let mut carried = active.clone();
+            carried.push_str(&line);
+            for sequence in graphemes(&line).filter(|g| is_escape_sequence(g)) {
+                if !sequence.starts_with("\x1b[") || !sequence.ends_with('m') {
+                    continue;
+                }
+                if matches!(sequence, "\x1b[0m" | "\x1b[m") {
+                    active.clear();
+                } else {
+                    active.push_str(sequence);
+                }
+            }
+            if !active.is_empty() {
+                carried.push_str("\x1b[0m");
+            }
+            carried
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: &str = "\x1b[31m";
+    const BOLD: &str = "\x1b[1m";
+    const RESET: &str = "\x1b[0m";
+
+    #[test]
+    fn wraps_words() {
+        assert_eq!(
+            wrap_text("the quick brown fox", 10),
+            ["the quick", "brown fox"]
+        );
+        assert_eq!(wrap_text("abcdefgh", 3), ["abc", "def", "gh"]);
+        assert_eq!(wrap_text("one\n\ntwo", 10), ["one", "", "two"]);
+    }
+
+    #[test]
+    fn breaks_wide_words_between_graphemes() {
+        assert_eq!(
+            wrap_text("日本語テキスト", 5),
+            ["日本", "語テ", "キス", "ト"]
+        );
+    }
+
+    #[test]
+    fn carries_styles_across_lines() {
+        let text = format!("{RED}abcdefgh{RESET}");
+        assert_eq!(
+            wrap_text(&text, 4),
+            [format!("{RED}abcd{RESET}"), format!("{RED}efgh{RESET}")]
+        );
+
+        let text = format!("plain {BOLD}{RED}styled words{RESET} plain");
+        assert_eq!(
+            wrap_text(&text, 12),
+            [
+                format!("plain {BOLD}{RED}styled{RESET}"),
+                format!("{BOLD}{RED}words{RESET} plain"),
+            ]
+        );
+    }
+
+    #[test]
+    fn leaves_closed_styles_alone() {
+        let text = format!("{RED}red{RESET} and more text");
+        assert_eq!(
+            wrap_text(&text, 8),
+            [
+                format!("{RED}red{RESET} and"),
+                "more".to_string(),
+                "text".to_string()
+            ]
+        );
+    }
+}
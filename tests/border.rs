@@ -0,0 +1,95 @@
+use pinax::{
+    set_color_choice, BorderChars, BorderStyle, ColorChoice, Column, HeaderGroup, SeparatorPolicy,
+    Table,
+};
+use std::collections::BTreeSet;
+
+// A distinct glyph for every field, so the output shows which one was drawn where.
+fn chars() -> BorderChars {
+    BorderChars {
+        top_left: 'α',
+        top_right: 'β',
+        bottom_left: 'γ',
+        bottom_right: 'δ',
+        outer_horizontal: 'ε',
+        outer_vertical: 'ζ',
+        top_separator: 'η',
+        bottom_separator: 'θ',
+        left_separator: 'ι',
+        right_separator: 'κ',
+        horizontal: 'λ',
+        vertical: 'μ',
+        cross: 'ν',
+        inner_top_separator: 'ξ',
+        inner_bottom_separator: 'ο',
+        inner_left_separator: 'π',
+        inner_right_separator: 'ρ',
+        header_horizontal: 'σ',
+        header_cross: 'τ',
+        header_left: 'υ',
+        header_right: 'φ',
+        header_top_separator: 'χ',
+        header_bottom_separator: 'ψ',
+    }
+}
+
+fn column(header: &str) -> Column {
+    Column::new(header).with_padding(2)
+}
+
+#[test]
+fn custom_border_chars() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(column("A"))
+        .add_group(
+            HeaderGroup::new("Pair")
+                .add_column(column("B"))
+                .add_column(column("C")),
+        )
+        .add_column(column("D"))
+        .style(BorderStyle::Custom(chars()))
+        .separators(SeparatorPolicy::ruled())
+        .build();
+    t.add_rows(
+        [
+            ["a0", "b0", "", "d0"],
+            ["a1", "b1", "c1", "d1"],
+            ["a2", "wide", "", "d2"],
+            ["a3", "", "", "d3"],
+            ["a4", "b4", "c4", ""],
+        ]
+        .map(Vec::from),
+    );
+    t.merge_cells(0, 1, 1, 2);
+    t.merge_cells(2, 1, 2, 2);
+    t.merge_cells(3, 3, 2, 1);
+
+    let text = t.to_string();
+    assert_eq!(
+        text.lines().collect::<Vec<_>>(),
+        [
+            "αεεεεηεεεεεεεεεηεεεεβ",
+            "ζ A  μ  Pair   μ D  ζ",
+            "ζ    πλλλλξλλλλρ    ζ",
+            "ζ    μ B  μ C  μ    ζ",
+            "υσσσστσσσσψσσσστσσσσφ",
+            "ζ a0 μ b0      μ d0 ζ",
+            "ιλλλλνλλλλξλλλλνλλλλκ",
+            "ζ a1 μ b1 μ c1 μ d1 ζ",
+            "ιλλλλνλλλλολλλλνλλλλκ",
+            "ζ a2 μ wide    μ d2 ζ",
+            "ιλλλλρ         πλλλλκ",
+            "ζ a3 μ         μ d3 ζ",
+            "ιλλλλνλλλλξλλλλρ    ζ",
+            "ζ a4 μ b4 μ c4 μ    ζ",
+            "γεεεεθεεεεθεεεεθεεεεδ",
+        ]
+    );
+
+    // Header cells never span a column line right above the header line, so
+    // `header_top_separator` has nothing to mark in a table.
+    let used: BTreeSet<char> = text.chars().filter(|ch| ('α'..='ω').contains(ch)).collect();
+    let expected: BTreeSet<char> = ('α'..='ψ').filter(|&ch| ch != 'ς' && ch != 'χ').collect();
+    assert_eq!(used, expected);
+}
@@ -0,0 +1,147 @@
+use pinax::{Column, CsvOptions, Date, Format, HeaderGroup, Table, Value};
+use std::io;
+
+fn to_csv(table: &Table, options: &CsvOptions) -> String {
+    let mut out = Vec::new();
+    table.to_csv(&mut out, options).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn from_csv(text: &str, options: &CsvOptions) -> Table {
+    Table::from_csv(text.as_bytes(), options).unwrap()
+}
+
+fn sample() -> Table {
+    let mut t = Table::builder()
+        .add_column(Column::new("Name"))
+        .add_column(Column::new("Qty"))
+        .add_column(Column::new("Price").with_format(Format::currency("$")))
+        .add_column(Column::new("Since"))
+        .add_column(Column::new("Active"))
+        .build();
+    t.add_row(vec![
+        Value::from("plain"),
+        Value::from(3),
+        Value::from(1234.5),
+        Value::from(Date::new(2024, 2, 29).unwrap()),
+        Value::from(true),
+    ]);
+    t.add_row(vec![
+        Value::from("comma, \"quote\"\nnewline"),
+        Value::from(-12),
+        Value::from(0.25),
+        Value::Empty,
+        Value::from(false),
+    ]);
+    t
+}
+
+#[test]
+fn export_quotes_fields_that_need_it() {
+    assert_eq!(
+        to_csv(&sample(), &CsvOptions::new()),
+        "Name,Qty,Price,Since,Active\n\
+         plain,3,1234.5,2024-02-29,true\n\
+         \"comma, \"\"quote\"\"\nnewline\",-12,0.25,,false\n"
+    );
+}
+
+#[test]
+fn export_options() {
+    let t = sample();
+    assert_eq!(
+        to_csv(
+            &t,
+            &CsvOptions::new()
+                .with_formatted_values(true)
+                .with_header(false)
+        ),
+        "plain,3,\"$1,234.50\",2024-02-29,true\n\
+         \"comma, \"\"quote\"\"\nnewline\",-12,$0.25,,false\n"
+    );
+    assert_eq!(
+        to_csv(&t, &CsvOptions::tsv()),
+        "Name\tQty\tPrice\tSince\tActive\n\
+         plain\t3\t1234.5\t2024-02-29\ttrue\n\
+         \"comma, \"\"quote\"\"\nnewline\"\t-12\t0.25\t\tfalse\n"
+    );
+}
+
+#[test]
+fn export_views_and_header_groups() {
+    let mut t = Table::builder()
+        .add_column(Column::new("Id"))
+        .add_group(
+            HeaderGroup::new("Size")
+                .add_column(Column::new("W"))
+                .add_column(Column::new("H")),
+        )
+        .build();
+    t.add_row(vec![1, 10, 20]);
+    t.add_row(vec![2, 30, 40]);
+    let mut out = Vec::new();
+    t.view()
+        .select_columns(["H", "Id"])
+        .tail(1)
+        .to_csv(&mut out, &CsvOptions::new())
+        .unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "Size / H,Id\n40,2\n");
+}
+
+#[test]
+fn round_trip() {
+    let t = sample();
+    for options in [CsvOptions::new(), CsvOptions::tsv()] {
+        let back = from_csv(&to_csv(&t, &options), &options);
+        assert_eq!(back.to_json(), t.to_json());
+        assert_eq!(to_csv(&back, &options), to_csv(&t, &options));
+    }
+}
+
+#[test]
+fn import_infers_types() {
+    let t = from_csv(
+        "id,zip,score,ok,day,note\n1,01234,2.5,true,2024-01-31,\n",
+        &CsvOptions::new(),
+    );
+    assert_eq!(
+        t.to_json(),
+        "[\n  {\"id\":1,\"zip\":\"01234\",\"score\":2.5,\"ok\":true,\
+         \"day\":\"2024-01-31\",\"note\":null}\n]\n"
+    );
+
+    let t = from_csv(
+        "id,ok\n1,true\n",
+        &CsvOptions::new().with_type_inference(false),
+    );
+    assert_eq!(t.to_json(), "[\n  {\"id\":\"1\",\"ok\":\"true\"}\n]\n");
+}
+
+#[test]
+fn import_without_header() {
+    let t = from_csv("a\tb\nc\td\te\n", &CsvOptions::tsv().with_header(false));
+    assert_eq!(
+        to_csv(&t, &CsvOptions::new()),
+        "Column 1,Column 2,Column 3\na,b,\nc,d,e\n"
+    );
+}
+
+#[test]
+fn import_widens_to_the_longest_record() {
+    let t = from_csv("a,b\n1\n2,3,4\n", &CsvOptions::new());
+    assert_eq!(to_csv(&t, &CsvOptions::new()), "a,b,Column 3\n1,,\n2,3,4\n");
+}
+
+#[test]
+fn import_edge_cases() {
+    let t = from_csv("", &CsvOptions::new());
+    assert_eq!(to_csv(&t, &CsvOptions::new()), "\n");
+
+    let t = from_csv("a,b\r\n\"x,\r\ny\",2\r\n", &CsvOptions::new());
+    assert_eq!(to_csv(&t, &CsvOptions::new()), "a,b\n\"x,\r\ny\",2\n");
+
+    match Table::from_csv("a\n\"open".as_bytes(), &CsvOptions::new()) {
+        Err(error) => assert_eq!(error.kind(), io::ErrorKind::InvalidData),
+        Ok(_) => panic!("an unterminated quote is an error"),
+    }
+}
@@ -0,0 +1,105 @@
+use pinax::{set_color_choice, terminal_width, ColorChoice, Column, Overflow, Table};
+
+fn table() -> Table {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(Column::new("Id").with_padding(2).with_priority(3))
+        .add_column(Column::new("Name").with_padding(2).with_priority(2))
+        .add_column(
+            Column::new("Description")
+                .with_padding(2)
+                .with_priority(1)
+                .with_overflow(Overflow::Wrap),
+        )
+        .build();
+    t.add_row(vec!["1", "alpha", "the first letter of the alphabet"]);
+    t.add_row(vec!["2", "beta-longer-name", "second"]);
+    t
+}
+
+fn lines(text: impl ToString) -> Vec<String> {
+    text.to_string().lines().map(str::to_string).collect()
+}
+
+#[test]
+fn wide_enough_renders_naturally() {
+    let t = table();
+    assert_eq!(t.render_with_width(60), t.to_string());
+}
+
+#[test]
+fn lowest_priority_shrinks_first() {
+    assert_eq!(
+        lines(table().render_with_width(40)),
+        [
+            "┌────┬──────────────────┬──────────────┐",
+            "│ Id │ Name             │ Description  │",
+            "├────┼──────────────────┼──────────────┤",
+            "│ 1  │ alpha            │ the first    │",
+            "│    │                  │ letter of    │",
+            "│    │                  │ the alphabet │",
+            "│ 2  │ beta-longer-name │ second       │",
+            "└────┴──────────────────┴──────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn columns_stop_at_their_minimum_width() {
+    // "Description" stays as wide as its header, so "Name" gives up the rest.
+    assert_eq!(
+        lines(table().render_with_width(30)),
+        [
+            "┌────┬─────────┬─────────────┐",
+            "│ Id │ Name    │ Description │",
+            "├────┼─────────┼─────────────┤",
+            "│ 1  │ alpha   │ the first   │",
+            "│    │         │ letter of   │",
+            "│    │         │ the         │",
+            "│    │         │ alphabet    │",
+            "│ 2  │ beta... │ second      │",
+            "└────┴─────────┴─────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn columns_that_cannot_fit_are_hidden() {
+    assert_eq!(
+        lines(table().render_with_width(20)),
+        [
+            "┌────┬─────────────┐",
+            "│ Id │ Name        │",
+            "├────┼─────────────┤",
+            "│ 1  │ alpha       │",
+            "│ 2  │ beta-lon... │",
+            "└────┴─────────────┘",
+            "+1 hidden column",
+        ]
+    );
+    assert_eq!(
+        lines(table().render_with_width(12)),
+        [
+            "┌────┐",
+            "│ Id │",
+            "├────┤",
+            "│ 1  │",
+            "│ 2  │",
+            "└────┘",
+            "+2 hidden columns",
+        ]
+    );
+}
+
+#[test]
+fn the_last_column_overflows_instead_of_hiding() {
+    assert_eq!(table().render_with_width(3), table().render_with_width(12));
+}
+
+#[test]
+fn columns_variable_sets_the_terminal_width() {
+    std::env::set_var("COLUMNS", "40");
+    assert_eq!(terminal_width(), Some(40));
+    let t = table();
+    assert_eq!(t.render_for_terminal(), t.render_with_width(40));
+}
@@ -0,0 +1,150 @@
+use pinax::{set_color_choice, Aggregate, ColorChoice, Column, Format, Table, Value};
+
+fn table() -> Table {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(Column::new("Item").with_padding(2).with_footer("Total"))
+        .add_column(
+            Column::new("Qty")
+                .with_padding(2)
+                .with_aggregate(Aggregate::Sum),
+        )
+        .add_column(
+            Column::new("Price")
+                .with_padding(2)
+                .with_format(Format::currency("$"))
+                .with_aggregate(Aggregate::Mean),
+        )
+        .add_column(Column::new("Note").with_padding(2))
+        .build();
+    t.add_row(vec![
+        Value::from("apple"),
+        Value::from(3),
+        Value::from(1.25),
+        Value::from("x"),
+    ]);
+    t.add_row(vec![
+        Value::from("pear"),
+        Value::from(5),
+        Value::from(2.5),
+        Value::Empty,
+    ]);
+    t.add_row(vec![
+        Value::from("plum"),
+        Value::Empty,
+        Value::from("n/a"),
+        Value::from("y"),
+    ]);
+    t
+}
+
+fn lines(text: impl ToString) -> Vec<String> {
+    text.to_string().lines().map(str::to_string).collect()
+}
+
+#[test]
+fn footer_row() {
+    assert_eq!(
+        lines(table()),
+        [
+            "┌───────┬─────┬───────┬──────┐",
+            "│ Item  │ Qty │ Price │ Note │",
+            "├───────┼─────┼───────┼──────┤",
+            "│ apple │   3 │ $1.25 │ x    │",
+            "│ pear  │   5 │ $2.50 │      │",
+            "│ plum  │     │ n/a   │ y    │",
+            "├───────┼─────┼───────┼──────┤",
+            "│ Total │   8 │ $1.88 │      │",
+            "└───────┴─────┴───────┴──────┘",
+        ]
+    );
+}
+
+#[test]
+fn footer_follows_the_view() {
+    let t = table();
+    assert_eq!(
+        lines(t.view().filter(|row| row[0] != Value::from("pear"))),
+        [
+            "┌───────┬─────┬───────┬──────┐",
+            "│ Item  │ Qty │ Price │ Note │",
+            "├───────┼─────┼───────┼──────┤",
+            "│ apple │   3 │ $1.25 │ x    │",
+            "│ plum  │     │ n/a   │ y    │",
+            "├───────┼─────┼───────┼──────┤",
+            "│ Total │   3 │ $1.25 │      │",
+            "└───────┴─────┴───────┴──────┘",
+        ]
+    );
+    assert_eq!(
+        lines(t.view().select_columns(["Qty", "Note"])),
+        [
+            "┌─────┬──────┐",
+            "│ Qty │ Note │",
+            "├─────┼──────┤",
+            "│   3 │ x    │",
+            "│   5 │      │",
+            "│     │ y    │",
+            "├─────┼──────┤",
+            "│   8 │      │",
+            "└─────┴──────┘",
+        ]
+    );
+}
+
+#[test]
+fn no_footer_without_visible_summaries() {
+    let t = table();
+    let view = t.view().select_columns(["Note"]);
+    assert_eq!(
+        lines(&view),
+        [
+            "┌──────┐",
+            "│ Note │",
+            "├──────┤",
+            "│ x    │",
+            "│      │",
+            "│ y    │",
+            "└──────┘",
+        ]
+    );
+    assert!(!view.to_html().contains("<tfoot>"));
+    assert_eq!(view.to_markdown().lines().count(), 5);
+    assert!(t.view().to_html().contains("<tfoot>"));
+}
+
+#[test]
+fn footer_formats() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(
+            Column::new("Bytes")
+                .with_padding(2)
+                .with_format(Format::si("B"))
+                .with_footer_format(Format::number().thousands(','))
+                .with_aggregate(Aggregate::Sum),
+        )
+        .add_column(
+            Column::new("Kind")
+                .with_padding(2)
+                .with_aggregate(Aggregate::CountDistinct),
+        )
+        .build();
+    t.add_rows([
+        vec![Value::from(1500), Value::from("a")],
+        vec![Value::from(2_000_000), Value::from("b")],
+    ]);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌───────────┬──────┐",
+            "│     Bytes │ Kind │",
+            "├───────────┼──────┤",
+            "│    1.5 kB │ a    │",
+            "│    2.0 MB │ b    │",
+            "├───────────┼──────┤",
+            "│ 2,001,500 │    2 │",
+            "└───────────┴──────┘",
+        ]
+    );
+}
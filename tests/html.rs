@@ -0,0 +1,106 @@
+use pinax::{
+    Aggregate, Alignment, Color, Column, Condition, HeaderGroup, Rule, Style, Table, Value,
+};
+
+#[test]
+fn golden() {
+    let mut t = Table::builder()
+        .add_column(Column::new("Name <&>").with_footer("Total"))
+        .add_group(
+            HeaderGroup::new("Stock")
+                .add_column(Column::new("Qty").with_aggregate(Aggregate::Sum))
+                .add_column(Column::new("Where").with_alignment(Alignment::Center)),
+        )
+        .add_column(
+            Column::new("Note")
+                .with_style(Style::new().italic())
+                .with_rule(Rule::new(
+                    Condition::Contains("!".into()),
+                    Style::new().fg(Color::Red).bold(),
+                )),
+        )
+        .build();
+    t.add_row(vec![
+        Value::from("Tom & \"Jerry\""),
+        Value::from(3),
+        Value::from("<shelf>"),
+        Value::from("it's fine"),
+    ]);
+    t.add_row(vec![
+        Value::from("merged"),
+        Value::from(4),
+        Value::Empty,
+        Value::from("urgent!"),
+    ]);
+    t.add_row(vec![
+        Value::from("two\nlines"),
+        Value::from(5),
+        Value::from("back"),
+        Value::from("x"),
+    ]);
+    t.merge_cells(1, 1, 1, 2);
+    t.merge_cells(1, 3, 2, 1);
+    t.set_row_style(2, Style::new().bg(Color::Rgb(255, 136, 0)));
+
+    assert_eq!(
+        t.to_html().lines().collect::<Vec<_>>(),
+        [
+            "<table class=\"pinax\">",
+            "  <thead>",
+            "    <tr>",
+            "      <th rowspan=\"2\" style=\"text-align: left\">Name &lt;&amp;&gt;</th>",
+            "      <th colspan=\"2\" style=\"text-align: center\">Stock</th>",
+            "      <th rowspan=\"2\" style=\"text-align: left\">Note</th>",
+            "    </tr>",
+            "    <tr>",
+            "      <th style=\"text-align: right\">Qty</th>",
+            "      <th style=\"text-align: center\">Where</th>",
+            "    </tr>",
+            "  </thead>",
+            "  <tbody>",
+            "    <tr>",
+            "      <td style=\"text-align: left\">Tom &amp; &quot;Jerry&quot;</td>",
+            "      <td style=\"text-align: right\">3</td>",
+            "      <td style=\"text-align: center\">&lt;shelf&gt;</td>",
+            "      <td class=\"pinax-italic\" style=\"text-align: left\">it&#39;s fine</td>",
+            "    </tr>",
+            "    <tr>",
+            "      <td style=\"text-align: left\">merged</td>",
+            "      <td colspan=\"2\" style=\"text-align: right\">4</td>",
+            "      <td class=\"pinax-bold pinax-italic pinax-fg-red\" rowspan=\"2\" style=\"text-align: left\">urgent!</td>",
+            "    </tr>",
+            "    <tr>",
+            "      <td class=\"pinax-bg-rgb-ff8800\" style=\"text-align: left\">two<br>lines</td>",
+            "      <td class=\"pinax-bg-rgb-ff8800\" style=\"text-align: right\">5</td>",
+            "      <td class=\"pinax-bg-rgb-ff8800\" style=\"text-align: center\">back</td>",
+            "    </tr>",
+            "  </tbody>",
+            "  <tfoot>",
+            "    <tr>",
+            "      <td style=\"text-align: left\">Total</td>",
+            "      <td style=\"text-align: right\">12</td>",
+            "      <td style=\"text-align: center\"></td>",
+            "      <td class=\"pinax-italic\" style=\"text-align: left\"></td>",
+            "    </tr>",
+            "  </tfoot>",
+            "</table>",
+        ]
+    );
+}
+
+#[test]
+fn views_without_footer() {
+    let mut t = Table::builder()
+        .add_column(Column::new("A"))
+        .add_column(Column::new("B").with_aggregate(Aggregate::Count))
+        .build();
+    t.add_row(vec!["a0", "b0"]);
+    t.add_row(vec!["a1", "b1"]);
+    assert_eq!(
+        t.view().select_columns(["A"]).head(1).to_html(),
+        "<table class=\"pinax\">\n  <thead>\n    <tr>\n      \
+         <th style=\"text-align: left\">A</th>\n    </tr>\n  </thead>\n  \
+         <tbody>\n    <tr>\n      <td style=\"text-align: left\">a0</td>\n    \
+         </tr>\n  </tbody>\n</table>\n"
+    );
+}
@@ -0,0 +1,99 @@
+use pinax::{Column, Date, Table, Value};
+use std::time::Duration;
+
+fn table(headers: &[&str]) -> Table {
+    let mut builder = Table::builder();
+    for header in headers {
+        builder = builder.add_column(Column::new(*header));
+    }
+    builder.build()
+}
+
+#[test]
+fn escapes_strings() {
+    let mut t = table(&["say \"hi\""]);
+    t.add_row(vec!["a\"b\\c"]);
+    t.add_row(vec!["tab\there\nline\rend"]);
+    t.add_row(vec!["\u{1}\u{1f}\u{7f}\u{85}"]);
+    t.add_row(vec!["héllo 日本 🦀"]);
+    assert_eq!(
+        t.to_json(),
+        "[\n  \
+         {\"say \\\"hi\\\"\":\"a\\\"b\\\\c\"},\n  \
+         {\"say \\\"hi\\\"\":\"tab\\there\\nline\\rend\"},\n  \
+         {\"say \\\"hi\\\"\":\"\\u0001\\u001f\\u007f\\u0085\"},\n  \
+         {\"say \\\"hi\\\"\":\"héllo 日本 🦀\"}\n\
+         ]\n"
+    );
+}
+
+#[test]
+fn typed_values() {
+    let mut t = table(&["int", "float", "bool", "date", "duration", "empty"]);
+    t.add_row(vec![
+        Value::from(-7),
+        Value::from(2.5),
+        Value::from(true),
+        Value::from(Date::new(2024, 2, 29).unwrap()),
+        Value::from(Duration::from_millis(1500)),
+        Value::Empty,
+    ]);
+    assert_eq!(
+        t.to_json(),
+        "[\n  {\"int\":-7,\"float\":2.5,\"bool\":true,\"date\":\"2024-02-29\",\
+         \"duration\":1.5,\"empty\":null}\n]\n"
+    );
+}
+
+#[test]
+fn non_finite_floats_are_null() {
+    let mut t = table(&["x"]);
+    t.add_row(vec![f64::NAN]);
+    t.add_row(vec![f64::INFINITY]);
+    t.add_row(vec![f64::NEG_INFINITY]);
+    assert_eq!(
+        t.to_json(),
+        "[\n  {\"x\":null},\n  {\"x\":null},\n  {\"x\":null}\n]\n"
+    );
+}
+
+#[test]
+fn missing_cells_are_null() {
+    let mut t = table(&["a", "b"]);
+    t.add_row(vec!["only"]);
+    assert_eq!(t.to_json(), "[\n  {\"a\":\"only\",\"b\":null}\n]\n");
+}
+
+#[test]
+fn empty_table() {
+    let t = table(&["a"]);
+    assert_eq!(t.to_json(), "[]\n");
+    let mut out = Vec::new();
+    t.to_ndjson(&mut out).unwrap();
+    assert!(out.is_empty());
+}
+
+#[test]
+fn ndjson_is_one_object_per_line() {
+    let mut t = table(&["a", "b"]);
+    t.add_row(vec![Value::from("x\ny"), Value::from(1)]);
+    t.add_row(vec![Value::from("z"), Value::from(f64::NAN)]);
+    let mut out = Vec::new();
+    t.to_ndjson(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "{\"a\":\"x\\ny\",\"b\":1}\n{\"a\":\"z\",\"b\":null}\n"
+    );
+}
+
+#[test]
+fn views_select_rows_and_columns() {
+    let mut t = table(&["a", "b", "c"]);
+    t.add_row(vec!["a0", "b0", "c0"]);
+    t.add_row(vec!["a1", "b1", "c1"]);
+    let view = t.view().select_columns(["c", "a"]).tail(1);
+    assert_eq!(view.to_json(), "[\n  {\"c\":\"c1\",\"a\":\"a1\"}\n]\n");
+    let mut out = Vec::new();
+    view.to_ndjson(&mut out).unwrap();
+    assert_eq!(out, b"{\"c\":\"c1\",\"a\":\"a1\"}\n");
+}
@@ -0,0 +1,105 @@
+use pinax::{Aggregate, Alignment, Column, Format, Table, Value};
+
+fn lines(text: &str) -> Vec<&str> {
+    text.lines().collect()
+}
+
+#[test]
+fn alignment_markers() {
+    let mut t = Table::builder()
+        .add_column(Column::new("Name"))
+        .add_column(Column::new("Qty"))
+        .add_column(Column::new("Price").with_format(Format::currency("$")))
+        .add_column(Column::new("Left").with_alignment(Alignment::Left))
+        .add_column(Column::new("Mid").with_alignment(Alignment::Center))
+        .add_column(Column::new("Code").with_alignment(Alignment::Right))
+        .build();
+    t.add_row(vec![
+        Value::from("apple"),
+        Value::from(3),
+        Value::from(1.5),
+        Value::from("x"),
+        Value::from("m"),
+        Value::from("A1"),
+    ]);
+    t.add_row(vec![
+        Value::from("pear"),
+        Value::from(12),
+        Value::from(20),
+        Value::from("yy"),
+        Value::Empty,
+        Value::from("B22"),
+    ]);
+    assert_eq!(
+        lines(&t.to_markdown()),
+        [
+            "| Name  | Qty |  Price | Left | Mid | Code |",
+            "| ----- | --: | -----: | :--- | :-: | ---: |",
+            "| apple |   3 |  $1.50 | x    |  m  |   A1 |",
+            "| pear  |  12 | $20.00 | yy   |     |  B22 |",
+        ]
+    );
+}
+
+#[test]
+fn escaping() {
+    let mut t = Table::builder()
+        .add_column(Column::new("A|B"))
+        .add_column(Column::new("Text"))
+        .build();
+    t.add_row(vec!["a|b", "line\nbreak"]);
+    t.add_row(vec!["back\\slash", "crlf\r\nline"]);
+    assert_eq!(
+        lines(&t.to_markdown()),
+        [
+            "| A\\|B        | Text          |",
+            "| ----------- | ------------- |",
+            "| a\\|b        | line<br>break |",
+            "| back\\\\slash | crlf<br>line  |",
+        ]
+    );
+}
+
+#[test]
+fn footer_row() {
+    let mut t = Table::builder()
+        .add_column(Column::new("Item").with_footer("Total"))
+        .add_column(Column::new("Qty").with_aggregate(Aggregate::Sum))
+        .add_column(Column::new("Note"))
+        .build();
+    t.add_row(vec![Value::from("apple"), Value::from(3), Value::from("x")]);
+    t.add_row(vec![Value::from("pear"), Value::from(12), Value::Empty]);
+    assert_eq!(
+        lines(&t.to_markdown()),
+        [
+            "| Item      |    Qty | Note |",
+            "| --------- | -----: | ---- |",
+            "| apple     |      3 | x    |",
+            "| pear      |     12 |      |",
+            "| **Total** | **15** |      |",
+        ]
+    );
+}
+
+#[test]
+fn views_and_section_rows() {
+    let mut t = Table::builder()
+        .add_column(Column::new("A"))
+        .add_column(Column::new("B"))
+        .build();
+    t.add_section_row("Section");
+    t.add_row(vec!["1", "2"]);
+    assert_eq!(
+        lines(&t.to_markdown()),
+        [
+            "| A           | B   |",
+            "| ----------- | --- |",
+            "| **Section** |     |",
+            "| 1           | 2   |",
+        ]
+    );
+    assert_eq!(
+        lines(&t.view().select_columns(["B"]).tail(1).to_markdown()),
+        ["| B   |", "| --- |", "| 2   |"]
+    );
+}
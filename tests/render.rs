@@ -0,0 +1,313 @@
+use pinax::{
+    set_color_choice, ColorChoice, Column, CsvOptions, HeaderGroup, Overflow, RowLines,
+    SeparatorPolicy, SortOrder, Table, Value, VerticalAlignment,
+};
+
+fn column(header: &str) -> Column {
+    Column::new(header).with_padding(2)
+}
+
+fn abc() -> Table {
+    set_color_choice(ColorChoice::Never);
+    Table::builder()
+        .add_column(column("A"))
+        .add_column(column("B"))
+        .add_column(column("C"))
+        .build()
+}
+
+fn lines(text: impl ToString) -> Vec<String> {
+    text.to_string().lines().map(str::to_string).collect()
+}
+
+#[test]
+fn colspan() {
+    let mut t = abc();
+    t.add_rows(
+        [
+            ["wide cell", "", "c0"],
+            ["a1", "b1", "c1"],
+            ["a2", "b2", "c2"],
+        ]
+        .map(Vec::from),
+    );
+    t.merge_cells(0, 0, 1, 2);
+    t.merge_cells(2, 1, 1, 2);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌─────┬─────┬────┐",
+            "│ A   │ B   │ C  │",
+            "├─────┴─────┼────┤",
+            "│ wide cell │ c0 │",
+            "│ a1  │ b1  │ c1 │",
+            "│ a2  │ b2       │",
+            "└─────┴──────────┘",
+        ]
+    );
+}
+
+#[test]
+fn rowspan() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(column("Group"))
+        .add_column(column("Item"))
+        .add_column(column("Qty"))
+        .separators(SeparatorPolicy::default().with_row_lines(RowLines::All))
+        .build();
+    t.add_rows(
+        [
+            ["fruit", "apple", "1"],
+            ["", "pear", "2"],
+            ["veg", "leek", "3"],
+        ]
+        .map(Vec::from),
+    );
+    t.merge_cells(0, 0, 2, 1);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌───────┬───────┬─────┐",
+            "│ Group │ Item  │ Qty │",
+            "├───────┼───────┼─────┤",
+            "│ fruit │ apple │ 1   │",
+            "│       ├───────┼─────┤",
+            "│       │ pear  │ 2   │",
+            "├───────┼───────┼─────┤",
+            "│ veg   │ leek  │ 3   │",
+            "└───────┴───────┴─────┘",
+        ]
+    );
+}
+
+#[test]
+fn section_rows_and_blocks() {
+    let mut t = abc();
+    t.add_section_row("Section");
+    t.add_rows([["block", "", "c1"], ["", "", "c2"], ["a3", "b3", "c3"]].map(Vec::from));
+    t.merge_cells(1, 0, 2, 2);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────┬────┬────┐",
+            "│ A  │ B  │ C  │",
+            "├────┴────┴────┤",
+            "│ Section      │",
+            "│ block   │ c1 │",
+            "│         │ c2 │",
+            "│ a3 │ b3 │ c3 │",
+            "└────┴────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn nested_header_groups() {
+    set_color_choice(ColorChoice::Never);
+    let time = HeaderGroup::new("Time")
+        .add_column(column("Start"))
+        .add_column(column("End"));
+    let t = Table::builder()
+        .add_column(column("Id"))
+        .add_group(
+            HeaderGroup::new("Size")
+                .add_column(column("W"))
+                .add_column(column("H")),
+        )
+        .add_group(
+            HeaderGroup::new("Meta")
+                .add_group(time)
+                .add_column(column("Tag")),
+        )
+        .build();
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────┬───────┬───────────────────┐",
+            "│ Id │ Size  │       Meta        │",
+            "│    ├───┬───┼─────────────┬─────┤",
+            "│    │ W │ H │    Time     │ Tag │",
+            "│    │   │   ├───────┬─────┤     │",
+            "│    │   │   │ Start │ End │     │",
+            "└────┴───┴───┴───────┴─────┴─────┘",
+        ]
+    );
+}
+
+#[test]
+fn mixed_junctions() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(column("A"))
+        .add_group(
+            HeaderGroup::new("Pair")
+                .add_column(column("B"))
+                .add_column(column("C")),
+        )
+        .add_column(column("D"))
+        .separators(SeparatorPolicy::ruled())
+        .build();
+    t.add_rows(
+        [
+            ["a0", "b0", "c0", "d0"],
+            ["a1", "wide", "", "d1"],
+            ["a2", "", "", "d2"],
+            ["a3", "b3", "c3", "d3"],
+        ]
+        .map(Vec::from),
+    );
+    t.merge_cells(1, 1, 2, 2);
+    t.merge_cells(2, 3, 2, 1);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────┬─────────┬────┐",
+            "│ A  │  Pair   │ D  │",
+            "│    ├────┬────┤    │",
+            "│    │ B  │ C  │    │",
+            "├────┼────┼────┼────┤",
+            "│ a0 │ b0 │ c0 │ d0 │",
+            "├────┼────┴────┼────┤",
+            "│ a1 │ wide    │ d1 │",
+            "├────┤         ├────┤",
+            "│ a2 │         │ d2 │",
+            "├────┼────┬────┤    │",
+            "│ a3 │ b3 │ c3 │    │",
+            "└────┴────┴────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn no_visible_columns() {
+    let mut t = abc();
+    t.add_row(vec!["a0", "b0", "c0"]);
+    assert_eq!(
+        lines(t.select_columns(["Unknown"])),
+        ["┌┐", "││", "├┤", "││", "└┘"]
+    );
+    assert_eq!(lines(Table::builder().build()), ["┌┐", "││", "└┘"]);
+
+    let empty = Table::from_csv("".as_bytes(), &CsvOptions::new()).unwrap();
+    assert_eq!(lines(&empty), ["┌┐", "││", "└┘"]);
+}
+
+#[test]
+fn wrapped_rows_grow_in_height() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(column("Name"))
+        .add_column(
+            column("Notes")
+                .with_max_width(10)
+                .with_overflow(Overflow::Wrap),
+        )
+        .add_column(column("Cut").with_max_width(6))
+        .build();
+    t.add_row(vec!["one", "short", "abcdefghij"]);
+    t.add_row(vec!["two", "a longer note that wraps", "x"]);
+    t.add_row(vec!["three", "line one\nline two", "y"]);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌───────┬────────────┬────────┐",
+            "│ Name  │ Notes      │ Cut    │",
+            "├───────┼────────────┼────────┤",
+            "│ one   │ short      │ abc... │",
+            "│ two   │ a longer   │ x      │",
+            "│       │ note that  │        │",
+            "│       │ wraps      │        │",
+            "│ three │ line one   │ y      │",
+            "│       │ line two   │        │",
+            "└───────┴────────────┴────────┘",
+        ]
+    );
+}
+
+#[test]
+fn vertical_alignment() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(column("Top"))
+        .add_column(column("Middle").with_vertical_alignment(VerticalAlignment::Middle))
+        .add_column(column("Bottom").with_vertical_alignment(VerticalAlignment::Bottom))
+        .add_column(column("Tall"))
+        .build();
+    t.add_row(vec!["t", "m", "b", "1\n2\n3\n4\n5"]);
+    t.add_row(vec!["t", "m", "b", "1\n2\n3\n4"]);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌─────┬────────┬────────┬──────┐",
+            "│ Top │ Middle │ Bottom │ Tall │",
+            "├─────┼────────┼────────┼──────┤",
+            "│ t   │        │        │ 1    │",
+            "│     │        │        │ 2    │",
+            "│     │ m      │        │ 3    │",
+            "│     │        │        │ 4    │",
+            "│     │        │ b      │ 5    │",
+            "│ t   │        │        │ 1    │",
+            "│     │ m      │        │ 2    │",
+            "│     │        │        │ 3    │",
+            "│     │        │ b      │ 4    │",
+            "└─────┴────────┴────────┴──────┘",
+        ]
+    );
+}
+
+#[test]
+fn sorting_cuts_rowspans_short() {
+    let table = || {
+        set_color_choice(ColorChoice::Never);
+        let mut t = Table::builder()
+            .add_column(column("K"))
+            .add_column(column("V"))
+            .separators(SeparatorPolicy::ruled())
+            .build();
+        for (k, v) in [("a", 1), ("b", 2), ("c", 0), ("d", 3)] {
+            t.add_row(vec![Value::from(k), Value::from(v)]);
+        }
+        t.merge_cells(0, 0, 2, 1);
+        t
+    };
+
+    let mut t = table();
+    t.sort_by_column("V", SortOrder::Descending);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌───┬───┐",
+            "│ K │ V │",
+            "├───┼───┤",
+            "│ d │ 3 │",
+            "├───┼───┤",
+            "│   │ 2 │",
+            "├───┼───┤",
+            "│ a │ 1 │",
+            "├───┼───┤",
+            "│ c │ 0 │",
+            "└───┴───┘",
+        ]
+    );
+
+    // Rows that stay together keep their merged cell.
+    let mut t = table();
+    t.sort_by_column("V", SortOrder::Ascending);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌───┬───┐",
+            "│ K │ V │",
+            "├───┼───┤",
+            "│ c │ 0 │",
+            "├───┼───┤",
+            "│ a │ 1 │",
+            "│   ├───┤",
+            "│   │ 2 │",
+            "├───┼───┤",
+            "│ d │ 3 │",
+            "└───┴───┘",
+        ]
+    );
+}
@@ -0,0 +1,209 @@
+use pinax::{
+    set_color_choice, Color, ColorChoice, Column, Condition, Format, RowRule, Rule, Style, Table,
+    Value,
+};
+
+fn red() -> Style {
+    Style::new().fg(Color::Red)
+}
+
+fn table(column: Column, values: &[Value]) -> Table {
+    set_color_choice(ColorChoice::Always);
+    let mut t = Table::builder()
+        .add_column(column.with_padding(2))
+        .add_column(Column::new("Key").with_padding(2))
+        .build();
+    for (i, value) in values.iter().enumerate() {
+        t.add_row(vec![value.clone(), Value::from(format!("k{}", i))]);
+    }
+    t
+}
+
+fn numbers(column: Column) -> Table {
+    table(column, &(1..=10).map(Value::from).collect::<Vec<_>>())
+}
+
+// The body cells of `table` as drawn, without padding.
+fn body(table: &Table) -> Vec<Vec<String>> {
+    let text = table.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    lines[3..lines.len() - 1]
+        .iter()
+        .map(|line| {
+            line.split('│')
+                .filter(|cell| !cell.is_empty())
+                .map(|cell| cell.replace(' ', ""))
+                .collect()
+        })
+        .collect()
+}
+
+// The first column of `table`, with the cells `styled` painted in `style`.
+fn expected(table: &Table, styled: &[&str], style: Style) -> Vec<String> {
+    body(table)
+        .iter()
+        .map(|row| {
+            let plain = strip(&row[0]);
+            if styled.contains(&plain.as_str()) {
+                style.paint(&plain)
+            } else {
+                plain
+            }
+        })
+        .collect()
+}
+
+fn strip(cell: &str) -> String {
+    let mut out = String::new();
+    let mut chars = cell.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+fn first_column(table: &Table) -> Vec<String> {
+    body(table).into_iter().map(|row| row[0].clone()).collect()
+}
+
+#[test]
+fn comparisons() {
+    let t = numbers(Column::new("N").with_rule(Rule::new(Condition::LessThan(3.0), red())));
+    assert_eq!(first_column(&t), expected(&t, &["1", "2"], red()));
+
+    let t = numbers(Column::new("N").with_rule(Rule::new(Condition::GreaterThan(8.0), red())));
+    assert_eq!(first_column(&t), expected(&t, &["9", "10"], red()));
+
+    // Inclusive on both ends.
+    let t = numbers(Column::new("N").with_rule(Rule::new(Condition::Between(4.0, 6.0), red())));
+    assert_eq!(first_column(&t), expected(&t, &["4", "5", "6"], red()));
+}
+
+#[test]
+fn numeric_conditions_read_numeric_text_only() {
+    let values = ["5", "x", "12", ""].map(Value::from);
+    let t = table(
+        Column::new("N").with_rule(Rule::new(Condition::GreaterThan(4.0), red())),
+        &values,
+    );
+    assert_eq!(first_column(&t), expected(&t, &["5", "12"], red()));
+}
+
+#[test]
+fn text_conditions_see_the_formatted_cell() {
+    let values = [1.5, 2.25, 10.0].map(Value::from);
+    let column = Column::new("N")
+        .with_format(Format::number().decimals(2))
+        .with_rule(Rule::new(Condition::Equals("2.25".into()), red()))
+        .with_rule(Rule::new(
+            Condition::Contains(".50".into()),
+            Style::new().bold(),
+        ));
+    let t = table(column, &values);
+    assert_eq!(
+        first_column(&t),
+        [
+            Style::new().bold().paint("1.50"),
+            red().paint("2.25"),
+            "10.00".to_string(),
+        ]
+    );
+
+    let t = table(
+        Column::new("N").with_rule(Rule::new(Condition::custom(|text| text.len() > 1), red())),
+        &["a", "bb", "ccc"].map(Value::from),
+    );
+    assert_eq!(first_column(&t), expected(&t, &["bb", "ccc"], red()));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex() {
+    let values = ["abc-1", "def", "xyz-22"].map(Value::from);
+    let t = table(
+        Column::new("N").with_rule(Rule::new(Condition::matches(r"-\d+$").unwrap(), red())),
+        &values,
+    );
+    assert_eq!(first_column(&t), expected(&t, &["abc-1", "xyz-22"], red()));
+    assert!(Condition::matches("(").is_err());
+}
+
+#[test]
+fn top_and_bottom_percent() {
+    let t = numbers(Column::new("N").with_rule(Rule::new(Condition::TopPercent(20.0), red())));
+    assert_eq!(first_column(&t), expected(&t, &["9", "10"], red()));
+
+    // Partial ranks round up, to at least one cell.
+    let t = numbers(Column::new("N").with_rule(Rule::new(Condition::BottomPercent(15.0), red())));
+    assert_eq!(first_column(&t), expected(&t, &["1", "2"], red()));
+    let t = numbers(Column::new("N").with_rule(Rule::new(Condition::TopPercent(1.0), red())));
+    assert_eq!(first_column(&t), expected(&t, &["10"], red()));
+    let t = numbers(Column::new("N").with_rule(Rule::new(Condition::TopPercent(0.0), red())));
+    assert_eq!(first_column(&t), expected(&t, &[], red()));
+
+    // Ties at the threshold all match, and text does not count towards the rank.
+    let values = ["7", "3", "7", "n/a", "1"].map(Value::from);
+    let t = table(
+        Column::new("N").with_rule(Rule::new(Condition::TopPercent(25.0), red())),
+        &values,
+    );
+    assert_eq!(first_column(&t), expected(&t, &["7"], red()));
+}
+
+#[test]
+fn row_rules() {
+    let mut t = numbers(Column::new("N"));
+    t.add_row_rule(RowRule::new(
+        |row| row[0].as_f64().is_some_and(|n| n % 5.0 == 0.0),
+        red(),
+    ));
+    let rows = body(&t);
+    for row in &rows {
+        let styled = ["5", "10"].contains(&strip(&row[0]).as_str());
+        for cell in row {
+            let plain = strip(cell);
+            let want = if styled { red().paint(&plain) } else { plain };
+            assert_eq!(cell, &want);
+        }
+    }
+}
+
+#[test]
+fn precedence() {
+    let blue = Style::new().fg(Color::Blue);
+    let mut t = numbers(
+        Column::new("N")
+            .with_style(Style::new().italic())
+            .with_rule(Rule::new(Condition::GreaterThan(5.0), red()))
+            .with_rule(Rule::new(Condition::GreaterThan(7.0), blue.bold())),
+    );
+    t.add_row_rule(RowRule::new(
+        |_| true,
+        Style::new().fg(Color::Green).underline(),
+    ));
+    t.set_cell_style(9, 0, Style::new().fg(Color::Yellow));
+
+    let italic = Style::new().italic().underline();
+    assert_eq!(
+        first_column(&t)[4..],
+        [
+            // Only the row rule.
+            italic.fg(Color::Green).paint("5"),
+            // Later column rules win over earlier ones and row rules, and attributes
+            // add up.
+            italic.fg(Color::Red).paint("6"),
+            italic.fg(Color::Red).paint("7"),
+            italic.fg(Color::Blue).bold().paint("8"),
+            italic.fg(Color::Blue).bold().paint("9"),
+            // Cell styles win over every rule.
+            italic.fg(Color::Yellow).bold().paint("10"),
+        ]
+    );
+
+    t.set_rules_enabled(false);
+    assert_eq!(first_column(&t)[4], Style::new().italic().paint("5"));
+}
@@ -0,0 +1,267 @@
+use pinax::{set_color_choice, ColorChoice, Column, Grid, RowLines, SeparatorPolicy, Table};
+
+fn table(separators: SeparatorPolicy) -> Vec<String> {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(Column::new("A").with_padding(2))
+        .add_column(Column::new("B").with_padding(2))
+        .separators(separators)
+        .build();
+    for r in 0..5 {
+        t.add_row(vec![format!("a{}", r), format!("b{}", r)]);
+    }
+    lines(t)
+}
+
+fn grid(separators: SeparatorPolicy) -> Vec<String> {
+    set_color_choice(ColorChoice::Never);
+    let mut g = Grid::builder()
+        .dimensions(3, 2)
+        .separators(separators)
+        .build();
+    for r in 0..3 {
+        for c in 0..2 {
+            g.set(r, c, format!("{}{}", r, c));
+        }
+    }
+    lines(g)
+}
+
+fn lines(text: impl ToString) -> Vec<String> {
+    text.to_string().lines().map(str::to_string).collect()
+}
+
+#[test]
+fn default_policy() {
+    let separators = SeparatorPolicy::default();
+    assert_eq!(
+        table(separators),
+        [
+            "┌────┬────┐",
+            "│ A  │ B  │",
+            "├────┼────┤",
+            "│ a0 │ b0 │",
+            "│ a1 │ b1 │",
+            "│ a2 │ b2 │",
+            "│ a3 │ b3 │",
+            "│ a4 │ b4 │",
+            "└────┴────┘",
+        ]
+    );
+    assert_eq!(
+        grid(separators),
+        [
+            "┌────────────────────┬────────────────────┐",
+            "│         00         │         01         │",
+            "│         10         │         11         │",
+            "│         20         │         21         │",
+            "└────────────────────┴────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn row_lines_all() {
+    let separators = SeparatorPolicy::default().with_row_lines(RowLines::All);
+    assert_eq!(
+        table(separators),
+        [
+            "┌────┬────┐",
+            "│ A  │ B  │",
+            "├────┼────┤",
+            "│ a0 │ b0 │",
+            "├────┼────┤",
+            "│ a1 │ b1 │",
+            "├────┼────┤",
+            "│ a2 │ b2 │",
+            "├────┼────┤",
+            "│ a3 │ b3 │",
+            "├────┼────┤",
+            "│ a4 │ b4 │",
+            "└────┴────┘",
+        ]
+    );
+    assert_eq!(
+        grid(separators),
+        [
+            "┌────────────────────┬────────────────────┐",
+            "│         00         │         01         │",
+            "├────────────────────┼────────────────────┤",
+            "│         10         │         11         │",
+            "├────────────────────┼────────────────────┤",
+            "│         20         │         21         │",
+            "└────────────────────┴────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn row_lines_every() {
+    let separators = SeparatorPolicy::default().with_row_lines(RowLines::Every(2));
+    assert_eq!(
+        table(separators),
+        [
+            "┌────┬────┐",
+            "│ A  │ B  │",
+            "├────┼────┤",
+            "│ a0 │ b0 │",
+            "│ a1 │ b1 │",
+            "├────┼────┤",
+            "│ a2 │ b2 │",
+            "│ a3 │ b3 │",
+            "├────┼────┤",
+            "│ a4 │ b4 │",
+            "└────┴────┘",
+        ]
+    );
+    assert_eq!(
+        grid(separators),
+        [
+            "┌────────────────────┬────────────────────┐",
+            "│         00         │         01         │",
+            "│         10         │         11         │",
+            "├────────────────────┼────────────────────┤",
+            "│         20         │         21         │",
+            "└────────────────────┴────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn no_header_line() {
+    let separators = SeparatorPolicy::default().with_header_line(false);
+    assert_eq!(
+        table(separators),
+        [
+            "┌────┬────┐",
+            "│ A  │ B  │",
+            "│ a0 │ b0 │",
+            "│ a1 │ b1 │",
+            "│ a2 │ b2 │",
+            "│ a3 │ b3 │",
+            "│ a4 │ b4 │",
+            "└────┴────┘",
+        ]
+    );
+    // Grids have no header, so they look like the default.
+    assert_eq!(
+        grid(separators),
+        [
+            "┌────────────────────┬────────────────────┐",
+            "│         00         │         01         │",
+            "│         10         │         11         │",
+            "│         20         │         21         │",
+            "└────────────────────┴────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn no_column_lines() {
+    let separators = SeparatorPolicy::ruled().with_column_lines(false);
+    assert_eq!(
+        table(separators),
+        [
+            "┌─────────┐",
+            "│ A    B  │",
+            "├─────────┤",
+            "│ a0   b0 │",
+            "├─────────┤",
+            "│ a1   b1 │",
+            "├─────────┤",
+            "│ a2   b2 │",
+            "├─────────┤",
+            "│ a3   b3 │",
+            "├─────────┤",
+            "│ a4   b4 │",
+            "└─────────┘",
+        ]
+    );
+    // Columns stay one space apart.
+    assert_eq!(
+        grid(separators),
+        [
+            "┌─────────────────────────────────────────┐",
+            "│         00                   01         │",
+            "├─────────────────────────────────────────┤",
+            "│         10                   11         │",
+            "├─────────────────────────────────────────┤",
+            "│         20                   21         │",
+            "└─────────────────────────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn no_frame() {
+    let separators = SeparatorPolicy::default().with_frame(false);
+    assert_eq!(
+        table(separators),
+        [
+            " A  │ B  ",
+            "────┼────",
+            " a0 │ b0 ",
+            " a1 │ b1 ",
+            " a2 │ b2 ",
+            " a3 │ b3 ",
+            " a4 │ b4 ",
+        ]
+    );
+    assert_eq!(
+        grid(separators),
+        [
+            "         00         │         01         ",
+            "         10         │         11         ",
+            "         20         │         21         ",
+        ]
+    );
+}
+
+#[test]
+fn no_outer_rules() {
+    let separators = SeparatorPolicy::default().with_outer_rules(false);
+    assert_eq!(
+        table(separators),
+        [
+            "│ A  │ B  │",
+            "├────┼────┤",
+            "│ a0 │ b0 │",
+            "│ a1 │ b1 │",
+            "│ a2 │ b2 │",
+            "│ a3 │ b3 │",
+            "│ a4 │ b4 │",
+        ]
+    );
+    assert_eq!(
+        grid(separators),
+        [
+            "│         00         │         01         │",
+            "│         10         │         11         │",
+            "│         20         │         21         │",
+        ]
+    );
+}
+
+#[test]
+fn compact() {
+    let separators = SeparatorPolicy::compact();
+    assert_eq!(
+        table(separators),
+        [
+            " A    B  ",
+            " a0   b0 ",
+            " a1   b1 ",
+            " a2   b2 ",
+            " a3   b3 ",
+            " a4   b4 ",
+        ]
+    );
+    assert_eq!(
+        grid(separators),
+        [
+            "         00                   01         ",
+            "         10                   11         ",
+            "         20                   21         ",
+        ]
+    );
+}
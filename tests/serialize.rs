@@ -0,0 +1,127 @@
+#![cfg(feature = "serde")]
+
+use pinax::{CsvOptions, Table};
+use serde::Serialize;
+
+fn csv(table: &Table) -> String {
+    let mut out = Vec::new();
+    table.to_csv(&mut out, &CsvOptions::new()).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[derive(Serialize)]
+struct Address {
+    city: &'static str,
+    zip: u32,
+}
+
+#[derive(Serialize)]
+struct Person {
+    name: &'static str,
+    addr: Option<Address>,
+    age: Option<u8>,
+    tags: Vec<&'static str>,
+}
+
+#[test]
+fn flattens_nested_fields() {
+    let people = [Person {
+        name: "Ada",
+        addr: Some(Address {
+            city: "London",
+            zip: 1815,
+        }),
+        age: Some(36),
+        tags: vec!["math", "engines"],
+    }];
+    let table = Table::from_serialize(&people).unwrap();
+    assert_eq!(
+        csv(&table),
+        "name,addr.city,addr.zip,age,tags\nAda,London,1815,36,\"math, engines\"\n"
+    );
+}
+
+#[test]
+fn missing_nested_struct_adds_no_column() {
+    let people = [
+        Person {
+            name: "Ada",
+            addr: None,
+            age: None,
+            tags: vec![],
+        },
+        Person {
+            name: "Alan",
+            addr: Some(Address {
+                city: "Wilmslow",
+                zip: 1912,
+            }),
+            age: None,
+            tags: vec!["turing"],
+        },
+    ];
+    let table = Table::from_serialize(&people).unwrap();
+    assert_eq!(
+        csv(&table),
+        "name,addr.city,addr.zip,age,tags\nAda,,,,\nAlan,Wilmslow,1912,,turing\n"
+    );
+}
+
+#[test]
+fn nested_struct_missing_from_later_rows() {
+    #[derive(Serialize)]
+    struct Row {
+        id: u8,
+        inner: Option<Address>,
+        unit: (),
+    }
+    let rows = [
+        Row {
+            id: 1,
+            inner: Some(Address {
+                city: "Oslo",
+                zip: 150,
+            }),
+            unit: (),
+        },
+        Row {
+            id: 2,
+            inner: None,
+            unit: (),
+        },
+    ];
+    let table = Table::from_serialize(&rows).unwrap();
+    assert_eq!(
+        csv(&table),
+        "id,inner.city,inner.zip,unit\n1,Oslo,150,\n2,,,\n"
+    );
+}
+
+#[test]
+fn tuples_and_enums() {
+    #[derive(Serialize)]
+    enum Shape {
+        Point,
+        Circle { r: f64 },
+    }
+    #[derive(Serialize)]
+    struct Row {
+        pos: (i32, i32),
+        shape: Shape,
+    }
+    let rows = [
+        Row {
+            pos: (1, -2),
+            shape: Shape::Point,
+        },
+        Row {
+            pos: (3, 4),
+            shape: Shape::Circle { r: 0.5 },
+        },
+    ];
+    let table = Table::from_serialize(&rows).unwrap();
+    assert_eq!(
+        csv(&table),
+        "pos.0,pos.1,shape,shape.Circle.r\n1,-2,Point,\n3,4,,0.5\n"
+    );
+}
@@ -0,0 +1,208 @@
+use pinax::{
+    set_color_choice, ColorChoice, Column, Ellipsis, Overflow, StreamingTableBuilder,
+    TruncatePosition, Truncation,
+};
+use std::{cell::RefCell, io, rc::Rc};
+
+// A writer whose output can be read while the table still holds it.
+#[derive(Clone, Default)]
+struct Shared(Rc<RefCell<Vec<u8>>>);
+
+impl Shared {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl io::Write for Shared {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn lines(bytes: Vec<u8>) -> Vec<String> {
+    String::from_utf8(bytes)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn header_is_written_right_away() {
+    set_color_choice(ColorChoice::Never);
+    let out = Shared::default();
+    let mut t = StreamingTableBuilder::new()
+        .add_column(Column::new("Name").with_padding(2))
+        .add_column(Column::new("Qty").with_padding(2))
+        .build(out.clone())
+        .unwrap();
+    assert_eq!(out.text(), "┌──────┬──────┐\n│ Name │ Qty  │\n");
+
+    t.write_row(vec!["a", "1"]).unwrap();
+    assert_eq!(
+        out.text(),
+        "┌──────┬──────┐\n│ Name │ Qty  │\n├──────┼──────┤\n│ a    │ 1    │\n"
+    );
+    t.finish().unwrap();
+}
+
+#[test]
+fn sampled_rows_are_held_back() {
+    set_color_choice(ColorChoice::Never);
+    let out = Shared::default();
+    let mut t = StreamingTableBuilder::new()
+        .add_column(Column::new("Name").with_padding(2))
+        .add_column(Column::new("Qty").with_padding(2))
+        .sample_rows(2)
+        .build(out.clone())
+        .unwrap();
+    t.write_row(vec!["first row", "1"]).unwrap();
+    assert_eq!(out.text(), "");
+
+    t.write_row(vec!["b", "12345"]).unwrap();
+    t.write_row(vec!["later and longer", "1"]).unwrap();
+    t.finish().unwrap();
+    assert_eq!(
+        lines(out.0.take()),
+        [
+            "┌───────────┬───────┐",
+            "│ Name      │ Qty   │",
+            "├───────────┼───────┤",
+            "│ first row │ 1     │",
+            "│ b         │ 12345 │",
+            "│ later ... │ 1     │",
+            "└───────────┴───────┘",
+        ]
+    );
+}
+
+#[test]
+fn finish_writes_short_samples_and_closes_the_border() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = StreamingTableBuilder::new()
+        .add_column(Column::new("A").with_padding(2))
+        .sample_rows(10)
+        .build(Vec::new())
+        .unwrap();
+    t.write_row(vec!["wide value"]).unwrap();
+    assert_eq!(
+        lines(t.finish().unwrap()),
+        [
+            "┌────────────┐",
+            "│ A          │",
+            "├────────────┤",
+            "│ wide value │",
+            "└────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn finish_without_rows() {
+    set_color_choice(ColorChoice::Never);
+    let t = StreamingTableBuilder::new()
+        .add_column(Column::new("Name").with_padding(2))
+        .build(Vec::new())
+        .unwrap();
+    assert_eq!(
+        lines(t.finish().unwrap()),
+        ["┌──────┐", "│ Name │", "└──────┘"]
+    );
+}
+
+#[test]
+fn fixed_widths() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = StreamingTableBuilder::new()
+        .add_column(Column::new("Id").with_width(6).with_padding(2))
+        .add_column(
+            Column::new("Path")
+                .with_width(8)
+                .with_max_width(8)
+                .with_padding(2)
+                .with_truncation(Truncation::new(TruncatePosition::Start)),
+        )
+        .build(Vec::new())
+        .unwrap();
+    t.write_row(vec!["1", "/usr/share/doc"]).unwrap();
+    t.write_row(vec!["2", "/tmp"]).unwrap();
+    assert_eq!(
+        lines(t.finish().unwrap()),
+        [
+            "┌────────┬──────────┐",
+            "│ Id     │ Path     │",
+            "├────────┼──────────┤",
+            "│ 1      │ ...e/doc │",
+            "│ 2      │ /tmp     │",
+            "└────────┴──────────┘",
+        ]
+    );
+}
+
+#[test]
+fn narrow_columns_still_show_the_ellipsis() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = StreamingTableBuilder::new()
+        .add_column(Column::new("A").with_padding(2))
+        .add_column(
+            Column::new("B")
+                .with_padding(2)
+                .with_truncation(Truncation::default().with_ellipsis(Ellipsis::Unicode)),
+        )
+        .add_column(
+            Column::new("C")
+                .with_padding(2)
+                .with_truncation(Truncation::default().with_ellipsis(Ellipsis::None)),
+        )
+        .build(Vec::new())
+        .unwrap();
+    t.write_row(vec![22222, 22222, 22222]).unwrap();
+    t.write_row(vec![1, 1, 1]).unwrap();
+    assert_eq!(
+        lines(t.finish().unwrap()),
+        [
+            "┌──────┬────┬───┐",
+            "│ A    │ B  │ C │",
+            "├──────┼────┼───┤",
+            "│ 2... │ 2… │ 2 │",
+            "│    1 │  1 │ 1 │",
+            "└──────┴────┴───┘",
+        ]
+    );
+}
+
+#[test]
+fn wrapped_rows_grow_in_height() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = StreamingTableBuilder::new()
+        .add_column(Column::new("N").with_padding(2))
+        .add_column(
+            Column::new("Message")
+                .with_max_width(10)
+                .with_padding(2)
+                .with_overflow(Overflow::Wrap),
+        )
+        .build(Vec::new())
+        .unwrap();
+    t.write_row(vec!["1", "the quick brown fox"]).unwrap();
+    t.write_row(vec!["2", "jumps"]).unwrap();
+    assert_eq!(
+        lines(t.finish().unwrap()),
+        [
+            "┌──────┬─────────┐",
+            "│ N    │ Message │",
+            "├──────┼─────────┤",
+            "│ 1    │ the     │",
+            "│      │ quick   │",
+            "│      │ brown   │",
+            "│      │ fox     │",
+            "│ 2    │ jumps   │",
+            "└──────┴─────────┘",
+        ]
+    );
+}
@@ -0,0 +1,236 @@
+use pinax::{set_color_choice, Alignment, ColorChoice, Column, CsvOptions, Format, Table, Value};
+
+fn table(columns: &[&str]) -> Table {
+    set_color_choice(ColorChoice::Never);
+    columns
+        .iter()
+        .fold(Table::builder(), |builder, &name| {
+            builder.add_column(Column::new(name).with_padding(2))
+        })
+        .build()
+}
+
+fn lines(table: &Table) -> Vec<String> {
+    table.to_string().lines().map(str::to_string).collect()
+}
+
+#[test]
+fn headers_follow_the_alignment_of_their_cells() {
+    let mut t = table(&["Name", "Count", "Mixed"]);
+    t.add_row(vec![
+        Value::from("apples"),
+        Value::from(12),
+        Value::from("x"),
+    ]);
+    t.add_row(vec![
+        Value::from("pears"),
+        Value::from(1234567),
+        Value::from(3),
+    ]);
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────────┬─────────┬───────┐",
+            "│ Name   │   Count │ Mixed │",
+            "├────────┼─────────┼───────┤",
+            "│ apples │      12 │ x     │",
+            "│ pears  │ 1234567 │     3 │",
+            "└────────┴─────────┴───────┘",
+        ]
+    );
+}
+
+#[test]
+fn headers_of_formatted_or_aligned_columns() {
+    set_color_choice(ColorChoice::Never);
+    let mut t = Table::builder()
+        .add_column(
+            Column::new("Share")
+                .with_padding(2)
+                .with_format(Format::percent()),
+        )
+        .add_column(
+            Column::new("Label")
+                .with_padding(2)
+                .with_alignment(Alignment::Center),
+        )
+        .build();
+    t.add_row(vec![Value::Empty, Value::from("ab")]);
+    assert_eq!(lines(&t)[1], "│ Share │ Label │");
+    t.add_row(vec![Value::from(0.5), Value::from(1)]);
+    assert_eq!(lines(&t)[1], "│ Share │ Label │");
+    assert_eq!(lines(&t)[4], "│   50% │   1   │");
+}
+
+fn abc() -> Table {
+    let mut t = table(&["A", "B", "C"]);
+    for r in 0..3 {
+        t.add_row(vec![
+            format!("a{}", r),
+            format!("b{}", r),
+            format!("c{}", r),
+        ]);
+    }
+    t
+}
+
+fn csv(table: &Table) -> String {
+    let mut out = Vec::new();
+    table.to_csv(&mut out, &CsvOptions::new()).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn move_column_unmerges_split_spans_in_place() {
+    let mut t = abc();
+    t.merge_cells(0, 0, 1, 2);
+    t.move_column(0, 2);
+    assert_eq!(csv(&t), "B,C,A\n,c0,a0\nb1,c1,a1\nb2,c2,a2\n");
+    assert_eq!(
+        t.to_json(),
+        "[\n  {\"B\":null,\"C\":\"c0\",\"A\":\"a0\"},\n  \
+         {\"B\":\"b1\",\"C\":\"c1\",\"A\":\"a1\"},\n  \
+         {\"B\":\"b2\",\"C\":\"c2\",\"A\":\"a2\"}\n]\n"
+    );
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────┬────┬────┐",
+            "│ B  │ C  │ A  │",
+            "├────┼────┼────┤",
+            "│    │ c0 │ a0 │",
+            "│ b1 │ c1 │ a1 │",
+            "│ b2 │ c2 │ a2 │",
+            "└────┴────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn move_column_keeps_contiguous_spans_merged() {
+    let mut t = abc();
+    t.merge_cells(0, 0, 1, 2);
+    t.move_column(2, 0);
+    assert_eq!(csv(&t), "C,A,B\nc0,a0,\nc1,a1,b1\nc2,a2,b2\n");
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────┬────┬────┐",
+            "│ C  │ A  │ B  │",
+            "├────┼────┴────┤",
+            "│ c0 │ a0      │",
+            "│ c1 │ a1 │ b1 │",
+            "│ c2 │ a2 │ b2 │",
+            "└────┴────┴────┘",
+        ]
+    );
+
+    // Reordering the columns inside a span keeps the value in its top-left cell.
+    let mut t = abc();
+    t.merge_cells(0, 1, 1, 2);
+    t.move_column(2, 1);
+    assert_eq!(csv(&t), "A,C,B\na0,b0,\na1,c1,b1\na2,c2,b2\n");
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────┬────┬────┐",
+            "│ A  │ C  │ B  │",
+            "├────┼────┴────┤",
+            "│ a0 │ b0      │",
+            "│ a1 │ c1 │ b1 │",
+            "│ a2 │ c2 │ b2 │",
+            "└────┴────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn insert_row_ends_spans_above_it() {
+    let mut t = abc();
+    t.merge_cells(0, 0, 3, 1);
+    t.insert_row(1, vec!["x-long-value", "x", "x"]);
+    assert_eq!(
+        csv(&t),
+        "A,B,C\na0,b0,c0\nx-long-value,x,x\n,b1,c1\n,b2,c2\n"
+    );
+    assert_eq!(
+        lines(&t),
+        [
+            "┌──────────────┬────┬────┐",
+            "│ A            │ B  │ C  │",
+            "├──────────────┼────┼────┤",
+            "│ a0           │ b0 │ c0 │",
+            "│ x-long-value │ x  │ x  │",
+            "│              │ b1 │ c1 │",
+            "│              │ b2 │ c2 │",
+            "└──────────────┴────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn remove_row_shrinks_spans_and_widths() {
+    let mut t = abc();
+    t.merge_cells(0, 0, 3, 1);
+    t.set_cell(1, 1, "widest-b");
+    assert_eq!(
+        t.remove_row(1),
+        Some(vec![
+            Value::Empty,
+            Value::from("widest-b"),
+            Value::from("c1")
+        ])
+    );
+    assert_eq!(csv(&t), "A,B,C\na0,b0,c0\n,b2,c2\n");
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────┬────┬────┐",
+            "│ A  │ B  │ C  │",
+            "├────┼────┼────┤",
+            "│ a0 │ b0 │ c0 │",
+            "│    │ b2 │ c2 │",
+            "└────┴────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn retain_shrinks_spans_and_widths() {
+    let mut t = abc();
+    t.merge_cells(0, 0, 3, 2);
+    t.set_cell(1, 2, "widest-c");
+    t.retain(|row| row[2] != Value::from("widest-c"));
+    assert_eq!(csv(&t), "A,B,C\na0,,c0\n,,c2\n");
+    assert_eq!(
+        lines(&t),
+        [
+            "┌───┬───┬────┐",
+            "│ A │ B │ C  │",
+            "├───┴───┼────┤",
+            "│ a0    │ c0 │",
+            "│       │ c2 │",
+            "└───────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn remove_column_hands_the_anchor_to_the_next_column() {
+    let mut t = abc();
+    t.merge_cells(0, 0, 2, 2);
+    assert!(t.remove_column(0).is_some());
+    assert_eq!(csv(&t), "B,C\na0,c0\n,c1\nb2,c2\n");
+    assert_eq!(
+        lines(&t),
+        [
+            "┌────┬────┐",
+            "│ B  │ C  │",
+            "├────┼────┤",
+            "│ a0 │ c0 │",
+            "│    │ c1 │",
+            "│ b2 │ c2 │",
+            "└────┴────┘",
+        ]
+    );
+}
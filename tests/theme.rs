@@ -0,0 +1,240 @@
+use pinax::{set_color_choice, ColorChoice, Column, Grid, Table, Theme, Value};
+
+fn table(theme: Theme) -> Vec<String> {
+    set_color_choice(ColorChoice::Never);
+    let mut table = Table::builder()
+        .theme(theme)
+        .add_column(Column::new("Crate").with_padding(2))
+        .add_column(Column::new("Downloads").with_padding(2))
+        .build();
+    table.add_row(vec![Value::from("serde"), Value::from(512)]);
+    table.add_row(vec![Value::from("pinax"), Value::from(7)]);
+    lines(table)
+}
+
+fn grid(theme: Theme) -> Vec<String> {
+    let mut grid = Grid::builder().dimensions(2, 2).theme(theme).build();
+    grid.set(0, 0, "a");
+    grid.set(0, 1, "b");
+    grid.set(1, 0, "c");
+    grid.set(1, 1, "d");
+    lines(grid)
+}
+
+fn lines(text: impl ToString) -> Vec<String> {
+    text.to_string().lines().map(str::to_string).collect()
+}
+
+#[test]
+fn ascii() {
+    assert_eq!(
+        table(Theme::Ascii),
+        [
+            "+-------+-----------+",
+            "| Crate | Downloads |",
+            "+-------+-----------+",
+            "| serde |       512 |",
+            "| pinax |         7 |",
+            "+-------+-----------+",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::Ascii),
+        [
+            "+--------------------+--------------------+",
+            "|         a          |         b          |",
+            "+--------------------+--------------------+",
+            "|         c          |         d          |",
+            "+--------------------+--------------------+",
+        ]
+    );
+}
+
+#[test]
+fn heavy() {
+    assert_eq!(
+        table(Theme::Heavy),
+        [
+            "┏━━━━━━━┳━━━━━━━━━━━┓",
+            "┃ Crate ┃ Downloads ┃",
+            "┣━━━━━━━╋━━━━━━━━━━━┫",
+            "┃ serde ┃       512 ┃",
+            "┃ pinax ┃         7 ┃",
+            "┗━━━━━━━┻━━━━━━━━━━━┛",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::Heavy),
+        [
+            "┏━━━━━━━━━━━━━━━━━━━━┳━━━━━━━━━━━━━━━━━━━━┓",
+            "┃         a          ┃         b          ┃",
+            "┣━━━━━━━━━━━━━━━━━━━━╋━━━━━━━━━━━━━━━━━━━━┫",
+            "┃         c          ┃         d          ┃",
+            "┗━━━━━━━━━━━━━━━━━━━━┻━━━━━━━━━━━━━━━━━━━━┛",
+        ]
+    );
+}
+
+#[test]
+fn markdown() {
+    assert_eq!(
+        table(Theme::Markdown),
+        [
+            "| Crate | Downloads |",
+            "|-------|-----------|",
+            "| serde |       512 |",
+            "| pinax |         7 |",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::Markdown),
+        [
+            "|         a          |         b          |",
+            "|--------------------|--------------------|",
+            "|         c          |         d          |",
+        ]
+    );
+}
+
+#[test]
+fn rst_grid() {
+    assert_eq!(
+        table(Theme::RstGrid),
+        [
+            "+-------+-----------+",
+            "| Crate | Downloads |",
+            "+=======+===========+",
+            "| serde |       512 |",
+            "+-------+-----------+",
+            "| pinax |         7 |",
+            "+-------+-----------+",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::RstGrid),
+        [
+            "+--------------------+--------------------+",
+            "|         a          |         b          |",
+            "+--------------------+--------------------+",
+            "|         c          |         d          |",
+            "+--------------------+--------------------+",
+        ]
+    );
+}
+
+#[test]
+fn rst_simple() {
+    assert_eq!(
+        table(Theme::RstSimple),
+        [
+            "======= ===========",
+            " Crate   Downloads ",
+            "======= ===========",
+            " serde         512 ",
+            " pinax           7 ",
+            "======= ===========",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::RstSimple),
+        [
+            "==================== ====================",
+            "         a                    b          ",
+            "==================== ====================",
+            "         c                    d          ",
+            "==================== ====================",
+        ]
+    );
+}
+
+#[test]
+fn psql() {
+    assert_eq!(
+        table(Theme::Psql),
+        [
+            " Crate | Downloads ",
+            "-------+-----------",
+            " serde |       512 ",
+            " pinax |         7 ",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::Psql),
+        [
+            "         a          |         b          ",
+            "--------------------+--------------------",
+            "         c          |         d          ",
+        ]
+    );
+}
+
+#[test]
+fn mysql() {
+    assert_eq!(
+        table(Theme::Mysql),
+        [
+            "+-------+-----------+",
+            "| Crate | Downloads |",
+            "+-------+-----------+",
+            "| serde |       512 |",
+            "| pinax |         7 |",
+            "+-------+-----------+",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::Mysql),
+        [
+            "+--------------------+--------------------+",
+            "|         a          |         b          |",
+            "+--------------------+--------------------+",
+            "|         c          |         d          |",
+            "+--------------------+--------------------+",
+        ]
+    );
+}
+
+#[test]
+fn blank() {
+    assert_eq!(
+        table(Theme::Blank),
+        [
+            " Crate   Downloads ",
+            "                   ",
+            " serde         512 ",
+            " pinax           7 ",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::Blank),
+        [
+            "         a                    b          ",
+            "                                         ",
+            "         c                    d          ",
+        ]
+    );
+}
+
+#[test]
+fn dots() {
+    assert_eq!(
+        table(Theme::Dots),
+        [
+            ".....................",
+            ": Crate : Downloads :",
+            ":.......:...........:",
+            ": serde :       512 :",
+            ": pinax :         7 :",
+            ":.......:...........:",
+        ]
+    );
+    assert_eq!(
+        grid(Theme::Dots),
+        [
+            "...........................................",
+            ":         a          :         b          :",
+            ":....................:....................:",
+            ":         c          :         d          :",
+            ":....................:....................:",
+        ]
+    );
+}
@@ -0,0 +1,87 @@
+use pinax::{set_color_choice, ColorChoice, Column, CsvOptions, Table, Value};
+
+fn table() -> Table {
+    set_color_choice(ColorChoice::Never);
+    let mut table = Table::builder()
+        .add_column(Column::new("A").with_padding(2))
+        .add_column(Column::new("B").with_padding(2))
+        .add_column(Column::new("C").with_padding(2))
+        .build();
+    table.add_rows([["a0", "b0", "c0"], ["a1", "b1", "c1"]].map(Vec::from));
+    table
+}
+
+fn lines(text: impl ToString) -> Vec<String> {
+    text.to_string().lines().map(str::to_string).collect()
+}
+
+#[test]
+fn select_columns_in_order() {
+    let t = table();
+    assert_eq!(
+        lines(t.select_columns(["C", "A"])),
+        [
+            "┌────┬────┐",
+            "│ C  │ A  │",
+            "├────┼────┤",
+            "│ c0 │ a0 │",
+            "│ c1 │ a1 │",
+            "└────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn select_columns_shows_repeated_columns_once() {
+    let t = table();
+    let view = t.select_columns(["A", "B", "A", "B"]);
+    assert_eq!(lines(&view), lines(t.select_columns(["A", "B"])));
+    let mut csv = Vec::new();
+    view.to_csv(&mut csv, &CsvOptions::new()).unwrap();
+    assert_eq!(String::from_utf8(csv).unwrap(), "A,B\na0,b0\na1,b1\n");
+}
+
+#[test]
+fn filter_and_slice() {
+    let t = table();
+    let view = t
+        .filter(|row| row[0] != Value::from("a0"))
+        .select_columns([1]);
+    assert_eq!(view.len(), 1);
+    assert_eq!(lines(view)[3], "│ b1 │");
+    assert!(t.slice(5..).is_empty());
+}
+
+#[test]
+fn group_by() {
+    let mut t = table();
+    t.add_row(vec!["a0", "b2", "c2"]);
+    assert_eq!(
+        lines(t.select_columns(["B", "C"]).group_by("A")),
+        [
+            "┌────┬────┐",
+            "│ B  │ C  │",
+            "├────┴────┤",
+            "│ A: a0   │",
+            "├────┬────┤",
+            "│ b0 │ c0 │",
+            "│ b2 │ c2 │",
+            "├────┴────┤",
+            "│ A: a1   │",
+            "├────┬────┤",
+            "│ b1 │ c1 │",
+            "└────┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn group_by_without_visible_columns() {
+    let t = table();
+    let grouped = t.select_columns(["Unknown"]).group_by("A");
+    assert_eq!(lines(&grouped), ["┌┐", "││", "├┤", "││", "├┤", "││", "└┘"]);
+    let grouped = grouped.with_subtotals(true).collapse_repeated(true);
+    assert!(lines(grouped.render_with_width(10))
+        .iter()
+        .all(|line| line.chars().count() == 2));
+}